@@ -1,10 +1,7 @@
 #![feature(test)]
 extern crate test;
 
-use {
-    nucleotide::{knucleotide, prev},
-    test::Bencher,
-};
+use {nucleotide::knucleotide, test::Bencher};
 
 #[cfg(test)]
 mod bench {
@@ -15,8 +12,9 @@ mod bench {
         b.iter(|| knucleotide::main());
     }
 
+    #[cfg(feature = "legacy")]
     #[bench]
     fn bench_prev(b: &mut Bencher) {
-        b.iter(|| prev::main());
+        b.iter(|| nucleotide::prev::main());
     }
 }
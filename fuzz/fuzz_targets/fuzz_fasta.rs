@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use nucleotide::fasta::parse_fasta_bytes;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_fasta_bytes(data);
+});
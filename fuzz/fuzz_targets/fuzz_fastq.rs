@@ -0,0 +1,7 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use nucleotide::fastq::parse_fastq_bytes;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = parse_fastq_bytes(data);
+});
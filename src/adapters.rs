@@ -0,0 +1,49 @@
+// Read-screening for adapter/barcode contamination over FASTQ reads: each
+// motif is looked for in every read with a plain substring scan; the
+// `knucleotide` pattern counter is not used here.
+
+// Imports --------------------------------------------------------------------
+use crate::fastq::read_fastq;
+
+// Types ----------------------------------------------------------------------
+pub struct AdapterReport {
+    pub reads_scanned: usize,
+    pub per_motif_hits: Vec<(String, usize)>,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn screen(path: &str, panel: Vec<String>) -> AdapterReport {
+    let reads = read_fastq(path);
+    let per_motif_hits = panel
+        .into_iter()
+        .map(|motif| {
+            let hits = reads
+                .iter()
+                .filter(|read| contains(&read.seq, motif.as_bytes()))
+                .count();
+            (motif, hits)
+        })
+        .collect();
+    AdapterReport {
+        reads_scanned: reads.len(),
+        per_motif_hits,
+    }
+}
+
+pub fn contamination_rate(report: &AdapterReport) -> Vec<(String, f32)> {
+    report
+        .per_motif_hits
+        .iter()
+        .map(|(motif, hits)| {
+            (
+                motif.clone(),
+                *hits as f32 * 100. / report.reads_scanned as f32,
+            )
+        })
+        .collect()
+}
+
+// Private Functions ------------------------------------------------------------
+fn contains(read: &[u8], motif: &[u8]) -> bool {
+    motif.len() <= read.len() && read.windows(motif.len()).any(|w| w == motif)
+}
@@ -0,0 +1,75 @@
+// Pairwise average nucleotide identity (ANI) estimate from shared k-mer
+// fraction, Mash-style: two genomes' k-mer sets overlap in proportion to how
+// similar they are, so the Jaccard index between their k-mer tables gives a
+// cheap alignment-free stand-in for actual ANI.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::SeqCounts;
+
+// Public Functions -------------------------------------------------------------
+/// Fraction of k-mers shared between `a` and `b`, ignoring counts (present
+/// or absent only), as `|A ∩ B| / |A ∪ B|`.
+pub fn jaccard(a: &SeqCounts, b: &SeqCounts) -> f64 {
+    let shared = a.keys().filter(|&seq| b.contains_key(seq)).count();
+    let union = a.len() + b.len() - shared;
+    if union == 0 {
+        return 1.0;
+    }
+    shared as f64 / union as f64
+}
+
+/// The Mash distance derived from the Jaccard index: `-1/k * ln(2j / (1+j))`.
+pub fn mash_distance(k: usize, a: &SeqCounts, b: &SeqCounts) -> f64 {
+    let j = jaccard(a, b);
+    if j == 0.0 {
+        return 1.0;
+    }
+    -1.0 / k as f64 * (2.0 * j / (1.0 + j)).ln()
+}
+
+/// Average nucleotide identity estimate, `1 - mash_distance`.
+pub fn estimate(k: usize, a: &SeqCounts, b: &SeqCounts) -> f64 {
+    1.0 - mash_distance(k, a, b)
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knucleotide::count_k;
+
+    #[test]
+    fn jaccard_of_identical_genomes_is_one() {
+        let genome = b"ACGT".repeat(20);
+        let counts = count_k(4, &genome);
+        assert_eq!(jaccard(&counts, &counts), 1.0);
+    }
+
+    #[test]
+    fn jaccard_of_disjoint_genomes_is_zero() {
+        let a = count_k(4, &b"ACGT".repeat(20));
+        let b = count_k(4, &b"TTTT".repeat(20));
+        assert_eq!(jaccard(&a, &b), 0.0);
+    }
+
+    #[test]
+    fn mash_distance_of_identical_genomes_is_zero() {
+        let genome = b"ACGT".repeat(20);
+        let counts = count_k(4, &genome);
+        assert_eq!(mash_distance(4, &counts, &counts), 0.0);
+    }
+
+    #[test]
+    fn mash_distance_of_disjoint_genomes_is_one() {
+        let a = count_k(4, &b"ACGT".repeat(20));
+        let b = count_k(4, &b"TTTT".repeat(20));
+        assert_eq!(mash_distance(4, &a, &b), 1.0);
+    }
+
+    #[test]
+    fn estimate_of_identical_genomes_is_one() {
+        let genome = b"ACGT".repeat(20);
+        let counts = count_k(4, &genome);
+        assert_eq!(estimate(4, &counts, &counts), 1.0);
+    }
+}
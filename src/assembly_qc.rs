@@ -0,0 +1,31 @@
+// Merqury-style assembly QC: given a read-derived k-mer count table and an
+// assembly, report how much of the assembly is supported by the reads. The
+// reverse direction (read k-mers found in the assembly) is not computed.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{count_k, SeqCounts};
+
+// Types ----------------------------------------------------------------------
+pub struct QcReport {
+    /// Fraction of assembly k-mers also seen in the read set.
+    pub completeness: f64,
+    /// Estimated consensus quality value, Merqury-style: -10*log10(error).
+    pub qv: f64,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn evaluate(k: usize, read_counts: &SeqCounts, assembly: &[u8]) -> QcReport {
+    let assembly_counts = count_k(k, assembly);
+    let total: usize = assembly_counts.len();
+    let supported = assembly_counts.keys().filter(|&seq| read_counts.contains_key(seq)).count();
+
+    let completeness = if total > 0 { supported as f64 / total as f64 } else { 0.0 };
+    let error_rate = if total > 0 {
+        1.0 - (supported as f64 / total as f64).powf(1.0 / k as f64)
+    } else {
+        0.0
+    };
+    let qv = if error_rate > 0.0 { -10.0 * error_rate.log10() } else { f64::INFINITY };
+
+    QcReport { completeness, qv }
+}
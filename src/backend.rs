@@ -0,0 +1,139 @@
+// A pluggable backend for k-mer count tables, so alternative storage
+// strategies (dense array, sketch, disk-backed) can share the same chunked
+// counting pipeline as the default hashbrown table.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::Sequence;
+use hashbrown::HashMap;
+
+// Types ----------------------------------------------------------------------
+pub trait CountBackend: Send {
+    fn insert(&mut self, seq: Sequence);
+    fn merge(self, other: Self) -> Self;
+    fn into_sorted(self) -> Vec<(Sequence, u32)>;
+}
+
+/// The default backend: an in-memory hash table, same as `SeqCounts`.
+#[derive(Default)]
+pub struct HashBackend(HashMap<Sequence, u32>);
+
+/// A fixed-size dense array backend, addressed directly by the packed key.
+/// Only practical for small k (k <= 12 keeps the table at or under 64MiB).
+pub struct DenseBackend {
+    counts: Vec<u32>,
+}
+
+// Public Functions -------------------------------------------------------------
+impl CountBackend for HashBackend {
+    fn insert(&mut self, seq: Sequence) {
+        *self.0.entry(seq).or_insert(0) += 1;
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        for (seq, count) in other.0 {
+            *self.0.entry(seq).or_insert(0) += count;
+        }
+        self
+    }
+
+    fn into_sorted(self) -> Vec<(Sequence, u32)> {
+        let mut v: Vec<_> = self.0.into_iter().collect();
+        v.sort_by(|(_, l), (_, r)| r.cmp(l));
+        v
+    }
+}
+
+impl DenseBackend {
+    /// `DenseBackend` has no useful zero-argument default — it needs `k` to
+    /// size its table — so unlike `HashBackend` it deliberately doesn't
+    /// implement `Default`; always construct it with `new(k)`.
+    pub fn new(k: usize) -> Self {
+        Self {
+            counts: vec![0; 4usize.pow(k as u32)],
+        }
+    }
+}
+
+impl CountBackend for DenseBackend {
+    fn insert(&mut self, seq: Sequence) {
+        self.counts[seq.key() as usize] += 1;
+    }
+
+    fn merge(mut self, other: Self) -> Self {
+        if self.counts.len() < other.counts.len() {
+            return other.merge(self);
+        }
+        for (i, count) in other.counts.into_iter().enumerate() {
+            self.counts[i] += count;
+        }
+        self
+    }
+
+    fn into_sorted(self) -> Vec<(Sequence, u32)> {
+        let mut v: Vec<(Sequence, u32)> = self
+            .counts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, count)| *count > 0)
+            .map(|(key, count)| (Sequence::from_key(key as u64), count))
+            .collect();
+        v.sort_by(|(_, l), (_, r)| r.cmp(l));
+        v
+    }
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_backend_inserts_and_merges_counts() {
+        let mut a = HashBackend::default();
+        a.insert(Sequence::from_key(0));
+        a.insert(Sequence::from_key(0));
+        a.insert(Sequence::from_key(1));
+
+        let mut b = HashBackend::default();
+        b.insert(Sequence::from_key(1));
+
+        let mut merged = a.merge(b).into_sorted();
+        merged.sort_by_key(|(seq, _)| seq.key());
+        assert_eq!(merged, vec![(Sequence::from_key(0), 2), (Sequence::from_key(1), 2)]);
+    }
+
+    #[test]
+    fn dense_backend_inserts_and_merges_counts() {
+        let mut a = DenseBackend::new(1);
+        a.insert(Sequence::from_key(0));
+        a.insert(Sequence::from_key(0));
+        a.insert(Sequence::from_key(1));
+
+        let mut b = DenseBackend::new(1);
+        b.insert(Sequence::from_key(1));
+
+        let mut merged = a.merge(b).into_sorted();
+        merged.sort_by_key(|(seq, _)| seq.key());
+        assert_eq!(merged, vec![(Sequence::from_key(0), 2), (Sequence::from_key(1), 2)]);
+    }
+
+    #[test]
+    fn dense_backend_merge_grows_to_whichever_side_has_the_larger_table() {
+        let small = DenseBackend::new(1);
+        let mut large = DenseBackend::new(2);
+        large.insert(Sequence::from_key(5));
+
+        let merged = small.merge(large).into_sorted();
+        assert_eq!(merged, vec![(Sequence::from_key(5), 1)]);
+    }
+
+    #[test]
+    fn count_k_with_agrees_across_backends() {
+        let genome = b"ACGT".repeat(20);
+        let mut hash_counts = crate::knucleotide::count_k_with(2, &genome, HashBackend::default).into_sorted();
+        let mut dense_counts = crate::knucleotide::count_k_with(2, &genome, || DenseBackend::new(2)).into_sorted();
+        hash_counts.sort_by_key(|(seq, _)| seq.key());
+        dense_counts.sort_by_key(|(seq, _)| seq.key());
+        assert_eq!(hash_counts, dense_counts);
+    }
+}
@@ -0,0 +1,50 @@
+// SAM/BAM input for read-based counting. Aligned reads are just another
+// source of sequence data for the counters in `knucleotide`, so this module
+// only concerns itself with pulling raw base strings out of a BAM file —
+// counting itself is left to the existing engine.
+
+// Imports --------------------------------------------------------------------
+use noodles_bam as bam;
+use std::io;
+use std::path::Path;
+
+// Public Functions -------------------------------------------------------------
+/// Reads every record's sequence out of a BAM file, in file order. Unmapped
+/// reads are included since read-based counting doesn't care about
+/// alignment, only base content.
+pub fn read_sequences(path: &Path) -> io::Result<Vec<Vec<u8>>> {
+    let mut reader = bam::reader::Builder::default().build_from_path(path)?;
+    let header = reader.read_header()?;
+    let mut sequences = Vec::new();
+    for result in reader.records(&header) {
+        let record = result?;
+        let bases = record.sequence().as_ref().iter().map(|base| u8::from(*base)).collect();
+        sequences.push(bases);
+    }
+    Ok(sequences)
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use noodles_sam::{self as sam, alignment::Record};
+
+    #[test]
+    fn reads_sequences_from_a_small_fixture_bam() {
+        let path = std::env::temp_dir().join(format!("nucleotide-bam-test-{:?}.bam", std::thread::current().id()));
+        let header = sam::Header::default();
+
+        let mut writer = bam::writer::Builder::default().build_from_path(&path).unwrap();
+        writer.write_header(&header).unwrap();
+        for sequence in ["ACGT", "GGCATTAG"] {
+            let record = Record::builder().set_sequence(sequence.parse().unwrap()).build();
+            writer.write_record(&header, &record).unwrap();
+        }
+        writer.try_finish().unwrap();
+        drop(writer);
+
+        let sequences = read_sequences(&path).unwrap();
+        assert_eq!(sequences, vec![b"ACGT".to_vec(), b"GGCATTAG".to_vec()]);
+    }
+}
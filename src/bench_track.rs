@@ -0,0 +1,56 @@
+// Benchmark baseline recording and regression checking, so timing
+// comparisons between `new` and `prev` are reproducible for contributors
+// without relying on CI history.
+
+// Imports --------------------------------------------------------------------
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::time::Duration;
+
+// Types ----------------------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+pub struct Baseline {
+    pub engine: String,
+    pub millis: f64,
+}
+
+pub struct Regression {
+    pub engine: String,
+    pub baseline_millis: f64,
+    pub observed_millis: f64,
+    pub percent_delta: f64,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn record(path: &Path, baselines: &[Baseline]) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(baselines).map_err(to_io_error)?;
+    std::fs::write(path, json)
+}
+
+pub fn load(path: &Path) -> std::io::Result<Vec<Baseline>> {
+    let text = std::fs::read_to_string(path)?;
+    serde_json::from_str(&text).map_err(to_io_error)
+}
+
+fn to_io_error(e: serde_json::Error) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, e)
+}
+
+/// Compares freshly-timed runs against the recorded baselines, flagging any
+/// engine that regressed by more than `fail_threshold_percent`.
+pub fn check(baselines: &[Baseline], observed: &[(String, Duration)], fail_threshold_percent: f64) -> Vec<Regression> {
+    observed
+        .iter()
+        .filter_map(|(engine, duration)| {
+            let baseline = baselines.iter().find(|b| &b.engine == engine)?;
+            let observed_millis = duration.as_secs_f64() * 1000.0;
+            let percent_delta = (observed_millis - baseline.millis) / baseline.millis * 100.0;
+            (percent_delta > fail_threshold_percent).then(|| Regression {
+                engine: engine.clone(),
+                baseline_millis: baseline.millis,
+                observed_millis,
+                percent_delta,
+            })
+        })
+        .collect()
+}
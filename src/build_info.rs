@@ -0,0 +1,47 @@
+// Build-info and capability introspection: lets an embedder ask "what was
+// this binary built with" (version, target, enabled features) without the
+// crate ever phoning home — everything here is resolved from compile-time
+// constants and `cfg` flags already baked into the binary.
+
+// Types ----------------------------------------------------------------------
+pub struct BuildInfo {
+    pub version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub features: Vec<&'static str>,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn current() -> BuildInfo {
+    BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        features: enabled_features(),
+    }
+}
+
+pub fn has_feature(name: &str) -> bool {
+    enabled_features().contains(&name)
+}
+
+// Private Functions ------------------------------------------------------------
+fn enabled_features() -> Vec<&'static str> {
+    let mut features = Vec::new();
+    if cfg!(feature = "legacy") {
+        features.push("legacy");
+    }
+    if cfg!(feature = "gpu") {
+        features.push("gpu");
+    }
+    if cfg!(feature = "bgzf") {
+        features.push("bgzf");
+    }
+    if cfg!(feature = "bam") {
+        features.push("bam");
+    }
+    if cfg!(feature = "toml-config") {
+        features.push("toml-config");
+    }
+    features
+}
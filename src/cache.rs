@@ -0,0 +1,31 @@
+// Result caching keyed by input checksum and parameters: a repeated run
+// against the same file with the same `k`/strategy shouldn't have to
+// recount from scratch. Piggybacks on the same `dump` format checkpointing
+// already uses.
+
+// Imports --------------------------------------------------------------------
+use crate::dump;
+use crate::knucleotide::SeqCounts;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+// Public Functions -------------------------------------------------------------
+/// Cache key derived from the input's checksum and the parameters that
+/// affect the resulting table (e.g. `k` and the counting strategy).
+pub fn cache_key(input_checksum: &str, params: &str) -> String {
+    format!("{input_checksum}-{:016x}", crate::manifest::fnv1a(params.as_bytes()))
+}
+
+pub fn cache_path(cache_dir: &Path, key: &str) -> PathBuf {
+    cache_dir.join(key).with_extension("cache")
+}
+
+pub fn get(cache_dir: &Path, key: &str) -> Option<SeqCounts> {
+    let f = File::open(cache_path(cache_dir, key)).ok()?;
+    dump::load(f).ok()
+}
+
+pub fn put(cache_dir: &Path, key: &str, counts: &SeqCounts) -> std::io::Result<()> {
+    std::fs::create_dir_all(cache_dir)?;
+    dump::dump(counts, File::create(cache_path(cache_dir, key))?)
+}
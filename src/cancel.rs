@@ -0,0 +1,35 @@
+// Cooperative cancellation for embedding applications (GUIs, servers) that
+// need to abort a long count promptly instead of killing worker threads.
+
+// Imports --------------------------------------------------------------------
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// Types ----------------------------------------------------------------------
+#[derive(Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+pub struct Cancelled;
+
+// Public Functions -------------------------------------------------------------
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
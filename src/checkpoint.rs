@@ -0,0 +1,55 @@
+// Checkpointing for multi-hour batch runs: partial count tables are dumped
+// per file so a `--resume` run can skip files it already finished.
+
+// Imports --------------------------------------------------------------------
+use crate::dump;
+use crate::knucleotide::SeqCounts;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+// Public Functions -------------------------------------------------------------
+/// Checkpoint file for `file` lives inside the checkpoint directory, named
+/// after `file`'s base name with its extension replaced by `.ckpt`
+/// (`reads.fa` -> `reads.ckpt`).
+pub fn checkpoint_path(dir: &Path, file: &str) -> PathBuf {
+    let name = Path::new(file).file_name().unwrap_or_default();
+    dir.join(name).with_extension("ckpt")
+}
+
+pub fn save(dir: &Path, file: &str, counts: &SeqCounts) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    dump::dump(counts, File::create(checkpoint_path(dir, file))?)
+}
+
+/// Returns the checkpointed counts for `file` if resuming, or `None` if this
+/// file hasn't been processed yet and needs to run.
+pub fn resume(dir: &Path, file: &str) -> Option<SeqCounts> {
+    let path = checkpoint_path(dir, file);
+    let f = File::open(path).ok()?;
+    dump::load(f).ok()
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knucleotide::count_k;
+
+    #[test]
+    fn resume_returns_none_before_a_checkpoint_exists() {
+        let dir = std::env::temp_dir().join(format!("nucleotide-checkpoint-test-{:?}-a", std::thread::current().id()));
+        assert!(resume(&dir, "reads.fa").is_none());
+    }
+
+    #[test]
+    fn save_then_resume_round_trips_the_counts() {
+        let dir = std::env::temp_dir().join(format!("nucleotide-checkpoint-test-{:?}-b", std::thread::current().id()));
+        let counts = count_k(4, &b"ACGT".repeat(20));
+
+        save(&dir, "reads.fa", &counts).unwrap();
+        let resumed = resume(&dir, "reads.fa").unwrap();
+        assert_eq!(resumed, counts);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// A small exit-code scheme and machine-readable error format for the CLI, so
+// workflow managers (Nextflow/Snakemake) can react to failures programmatically
+// instead of scraping stderr text.
+
+// Types ----------------------------------------------------------------------
+#[derive(Debug)]
+pub enum CliError {
+    BadInput(String),
+    Io(String),
+    ResourceLimit(String),
+}
+
+pub enum ErrorFormat {
+    Text,
+    Json,
+}
+
+// Public Functions -------------------------------------------------------------
+impl CliError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::BadInput(_) => 2,
+            CliError::Io(_) => 3,
+            CliError::ResourceLimit(_) => 4,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            CliError::BadInput(_) => "bad_input",
+            CliError::Io(_) => "io_error",
+            CliError::ResourceLimit(_) => "resource_limit",
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            CliError::BadInput(m) | CliError::Io(m) | CliError::ResourceLimit(m) => m,
+        }
+    }
+
+    /// Prints the error in `format` and returns the exit code the caller
+    /// should terminate the process with.
+    pub fn report(&self, format: ErrorFormat) -> i32 {
+        match format {
+            ErrorFormat::Text => eprintln!("error: {}", self.message()),
+            ErrorFormat::Json => eprintln!(
+                "{}",
+                serde_json::json!({"kind": self.kind(), "message": self.message(), "exit_code": self.exit_code()})
+            ),
+        }
+        self.exit_code()
+    }
+}
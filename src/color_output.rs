@@ -0,0 +1,30 @@
+// Colored, aligned human-readable terminal output: plain ANSI escapes (no
+// extra dependency) with columns padded to the widest entry, as an
+// alternative to the tab-separated `formatter::TsvFormatter` output.
+
+// Public Functions -------------------------------------------------------------
+const RESET: &str = "\x1b[0m";
+const CYAN: &str = "\x1b[36m";
+const YELLOW: &str = "\x1b[33m";
+
+/// Renders `rows` as `sequence  count` with both columns aligned, honoring
+/// `NO_COLOR` (https://no-color.org) by falling back to plain text.
+pub fn render(rows: &[(String, u32)]) -> String {
+    let colored = std::env::var_os("NO_COLOR").is_none();
+    let seq_width = rows.iter().map(|(seq, _)| seq.len()).max().unwrap_or(0);
+    let count_width = rows.iter().map(|(_, count)| count.to_string().len()).max().unwrap_or(0);
+
+    rows.iter()
+        .map(|(seq, count)| {
+            if colored {
+                format!(
+                    "{CYAN}{:<seq_width$}{RESET}  {YELLOW}{:>count_width$}{RESET}",
+                    seq, count
+                )
+            } else {
+                format!("{:<seq_width$}  {:>count_width$}", seq, count)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,77 @@
+// Per-k-mer frequency confidence intervals for subsampled runs: when
+// `sample::sample_max_bases` or `sample::sample_by_chunk` trims the input
+// before counting, each frequency is an estimate of the true genome-wide
+// value rather than an exact count, so it's worth reporting how wide that
+// estimate could plausibly be.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::SeqCounts;
+
+// Types ----------------------------------------------------------------------
+pub struct Interval {
+    pub estimate: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+// Public Functions -------------------------------------------------------------
+/// A Wilson score interval for the frequency of a single k-mer observed
+/// `count` times out of `total` k-mers, at the given z-score (1.96 for 95%).
+pub fn wilson_interval(count: u32, total: u32, z: f64) -> Interval {
+    if total == 0 {
+        return Interval { estimate: 0.0, low: 0.0, high: 0.0 };
+    }
+    let n = total as f64;
+    let p = count as f64 / n;
+    let z2 = z * z;
+    let denom = 1.0 + z2 / n;
+    let center = p + z2 / (2.0 * n);
+    let margin = z * ((p * (1.0 - p) / n) + z2 / (4.0 * n * n)).sqrt();
+    Interval {
+        estimate: p,
+        low: ((center - margin) / denom).max(0.0),
+        high: ((center + margin) / denom).min(1.0),
+    }
+}
+
+/// Computes a confidence interval for every k-mer's frequency in `counts`.
+pub fn confidence_intervals(k: usize, counts: &SeqCounts, z: f64) -> Vec<(String, Interval)> {
+    let total: u32 = counts.values().sum();
+    counts
+        .iter()
+        .map(|(seq, &count)| (seq.to_str(k), wilson_interval(count, total, z)))
+        .collect()
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knucleotide::count_k;
+
+    #[test]
+    fn wilson_interval_of_empty_sample_is_zero() {
+        let interval = wilson_interval(0, 0, 1.96);
+        assert_eq!(interval.estimate, 0.0);
+        assert_eq!(interval.low, 0.0);
+        assert_eq!(interval.high, 0.0);
+    }
+
+    #[test]
+    fn wilson_interval_brackets_the_point_estimate() {
+        let interval = wilson_interval(30, 100, 1.96);
+        assert_eq!(interval.estimate, 0.3);
+        assert!(interval.low < interval.estimate);
+        assert!(interval.estimate < interval.high);
+        assert!(interval.low >= 0.0);
+        assert!(interval.high <= 1.0);
+    }
+
+    #[test]
+    fn confidence_intervals_covers_every_kmer() {
+        let genome = b"ACGT".repeat(20);
+        let counts = count_k(4, &genome);
+        let intervals = confidence_intervals(4, &counts, 1.96);
+        assert_eq!(intervals.len(), counts.len());
+    }
+}
@@ -0,0 +1,318 @@
+// A builder for run configuration, loadable from a TOML or JSON profile file
+// so complex analyses are reproducible from a single file instead of a long
+// CLI invocation.
+
+// Imports --------------------------------------------------------------------
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+// Types ----------------------------------------------------------------------
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Config {
+    pub k_values: Vec<usize>,
+    pub patterns: Vec<String>,
+    pub strategy: String,
+    /// Output report format name, e.g. `"tsv"`, `"json"`, `"csv"`; the
+    /// `formatter` module has a renderer for each of these, but this field
+    /// only stores the name.
+    pub format: String,
+    pub threads: usize,
+    pub canonical: bool,
+}
+
+/// Mirrors `Config`'s fields as `Option`s so a profile file only has to
+/// specify the settings it wants to override. Deserializing straight into
+/// `Config` would require every field to be present, and would silently
+/// reset any field the file omits back to `Config::default()` instead of
+/// leaving it at whatever the environment already resolved.
+#[derive(Deserialize)]
+struct PartialConfig {
+    k_values: Option<Vec<usize>>,
+    patterns: Option<Vec<String>>,
+    strategy: Option<String>,
+    format: Option<String>,
+    threads: Option<usize>,
+    canonical: Option<bool>,
+}
+
+// Public Functions -------------------------------------------------------------
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            k_values: vec![1, 2],
+            patterns: Vec::new(),
+            strategy: "hash".into(),
+            format: "tsv".into(),
+            threads: num_cpus(),
+            canonical: false,
+        }
+    }
+}
+
+impl Config {
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder(Config::default())
+    }
+
+    /// Loads a profile from `path`, dispatching on its extension. Non-`.json`
+    /// extensions require the `toml-config` feature (on by default).
+    pub fn from_file(path: &Path) -> Result<Config, ConfigError> {
+        load_profile(path)
+    }
+
+    /// Resolves settings in precedence order, lowest to highest: built-in
+    /// defaults, then `NUCLEOTIDE_*` environment variables, then `path`'s
+    /// profile if given, then `cli_overrides` (e.g. `--format=json`) if any
+    /// are passed. Each source only touches the fields it actually
+    /// specifies, so e.g. an env override survives a profile file that
+    /// doesn't mention that field.
+    pub fn resolve(path: Option<&Path>, cli_overrides: &[(&str, &str)]) -> Result<Config, ConfigError> {
+        let mut config = Config::default().with_env_overrides();
+        if let Some(path) = path {
+            config = config.merge_partial(load_profile(path)?);
+        }
+        config.apply_cli_overrides(cli_overrides);
+        Ok(config)
+    }
+
+    fn with_env_overrides(mut self) -> Self {
+        if let Ok(strategy) = std::env::var("NUCLEOTIDE_STRATEGY") {
+            self.strategy = strategy;
+        }
+        if let Ok(format) = std::env::var("NUCLEOTIDE_FORMAT") {
+            self.format = format;
+        }
+        if let Some(threads) = std::env::var("NUCLEOTIDE_THREADS").ok().and_then(|v| v.parse().ok()) {
+            self.threads = threads;
+        }
+        if let Some(canonical) = std::env::var("NUCLEOTIDE_CANONICAL").ok().and_then(|v| v.parse().ok()) {
+            self.canonical = canonical;
+        }
+        if let Some(k_values) = std::env::var("NUCLEOTIDE_K_VALUES").ok().map(|v| {
+            v.split(',').filter_map(|s| s.trim().parse().ok()).collect::<Vec<usize>>()
+        }) {
+            if !k_values.is_empty() {
+                self.k_values = k_values;
+            }
+        }
+        self
+    }
+
+    fn merge_partial(mut self, partial: PartialConfig) -> Self {
+        if let Some(k_values) = partial.k_values {
+            self.k_values = k_values;
+        }
+        if let Some(patterns) = partial.patterns {
+            self.patterns = patterns;
+        }
+        if let Some(strategy) = partial.strategy {
+            self.strategy = strategy;
+        }
+        if let Some(format) = partial.format {
+            self.format = format;
+        }
+        if let Some(threads) = partial.threads {
+            self.threads = threads;
+        }
+        if let Some(canonical) = partial.canonical {
+            self.canonical = canonical;
+        }
+        self
+    }
+
+    /// Applies `--key=value` CLI flags, e.g. `("format", "json")`; unknown
+    /// keys are ignored so callers can pass through a whole argv without
+    /// pre-filtering it down to the flags this crate understands.
+    fn apply_cli_overrides(&mut self, overrides: &[(&str, &str)]) {
+        for &(key, value) in overrides {
+            match key {
+                "strategy" => self.strategy = value.into(),
+                "format" => self.format = value.into(),
+                "threads" => {
+                    if let Ok(threads) = value.parse() {
+                        self.threads = threads;
+                    }
+                }
+                "canonical" => {
+                    if let Ok(canonical) = value.parse() {
+                        self.canonical = canonical;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ConfigBuilder(Config);
+
+impl ConfigBuilder {
+    pub fn k_values(mut self, k_values: Vec<usize>) -> Self {
+        self.0.k_values = k_values;
+        self
+    }
+
+    pub fn patterns(mut self, patterns: Vec<String>) -> Self {
+        self.0.patterns = patterns;
+        self
+    }
+
+    pub fn strategy(mut self, strategy: impl Into<String>) -> Self {
+        self.0.strategy = strategy.into();
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.0.format = format.into();
+        self
+    }
+
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.0.threads = threads;
+        self
+    }
+
+    pub fn canonical(mut self, canonical: bool) -> Self {
+        self.0.canonical = canonical;
+        self
+    }
+
+    pub fn build(self) -> Config {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    #[cfg(feature = "toml-config")]
+    Toml(toml::de::Error),
+    /// A non-JSON profile was requested but the `toml-config` feature is
+    /// disabled in this build.
+    #[cfg(not(feature = "toml-config"))]
+    TomlUnsupported,
+}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(e: std::io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ConfigError {
+    fn from(e: serde_json::Error) -> Self {
+        ConfigError::Json(e)
+    }
+}
+
+#[cfg(feature = "toml-config")]
+impl From<toml::de::Error> for ConfigError {
+    fn from(e: toml::de::Error) -> Self {
+        ConfigError::Toml(e)
+    }
+}
+
+// Private Functions ------------------------------------------------------------
+/// Reads and deserializes a profile file, dispatching on its extension.
+/// Generic so both `Config::from_file` (which requires every field) and
+/// `resolve`'s merge path (which deserializes into `PartialConfig`) share
+/// the same extension handling.
+fn load_profile<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T, ConfigError> {
+    let text = std::fs::read_to_string(path)?;
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => Ok(serde_json::from_str(&text)?),
+        #[cfg(feature = "toml-config")]
+        _ => Ok(toml::from_str(&text)?),
+        #[cfg(not(feature = "toml-config"))]
+        _ => Err(ConfigError::TomlUnsupported),
+    }
+}
+
+fn num_cpus() -> usize {
+    std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `with_env_overrides` reads process-global environment variables;
+    // serialize the tests that touch them so they can't see each other's
+    // `set_var`/`remove_var` calls when cargo runs tests concurrently.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn merge_partial_only_touches_fields_the_file_specifies() {
+        let mut base = Config::default();
+        base.threads = 7;
+        base.strategy = "hash".into();
+
+        let partial = PartialConfig {
+            k_values: None,
+            patterns: None,
+            strategy: Some("minimizer".into()),
+            format: None,
+            threads: None,
+            canonical: None,
+        };
+        let merged = base.merge_partial(partial);
+
+        assert_eq!(merged.strategy, "minimizer");
+        assert_eq!(merged.threads, 7, "a field the file didn't mention must keep its prior value, not reset to Config::default()");
+    }
+
+    #[test]
+    fn apply_cli_overrides_wins_over_whatever_was_already_resolved() {
+        let mut config = Config::default();
+        config.format = "tsv".into();
+        config.apply_cli_overrides(&[("format", "json")]);
+        assert_eq!(config.format, "json");
+    }
+
+    #[test]
+    fn apply_cli_overrides_ignores_unknown_keys_and_bad_values() {
+        let mut config = Config::default();
+        let threads_before = config.threads;
+        config.apply_cli_overrides(&[("nonsense", "x"), ("threads", "not-a-number")]);
+        assert_eq!(config.threads, threads_before);
+    }
+
+    #[test]
+    fn resolve_lets_an_env_override_survive_a_file_that_omits_that_field() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("NUCLEOTIDE_THREADS", "11");
+
+        let dir = std::env::temp_dir().join(format!("nucleotide-config-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("profile.json");
+        std::fs::write(&path, r#"{"format": "json"}"#).unwrap();
+
+        let config = Config::resolve(Some(&path), &[]).unwrap();
+        assert_eq!(config.format, "json", "the file's own field should apply");
+        assert_eq!(config.threads, 11, "a field the file omitted must keep the env override, not reset to default");
+
+        std::env::remove_var("NUCLEOTIDE_THREADS");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn resolve_lets_a_cli_flag_beat_both_the_file_and_the_environment() {
+        let _guard = ENV_LOCK.lock().unwrap();
+        std::env::set_var("NUCLEOTIDE_FORMAT", "csv");
+
+        let dir = std::env::temp_dir().join(format!("nucleotide-config-test-{:?}-cli", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("profile.json");
+        std::fs::write(&path, r#"{"format": "json"}"#).unwrap();
+
+        let config = Config::resolve(Some(&path), &[("format", "tsv")]).unwrap();
+        assert_eq!(config.format, "tsv");
+
+        std::env::remove_var("NUCLEOTIDE_FORMAT");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
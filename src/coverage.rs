@@ -0,0 +1,30 @@
+// Alignment-free pseudo-coverage: for each position in a reference, the
+// count of the k-mer starting there in a read-derived count table, emitted
+// as a bedGraph track.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+
+// Public Functions -------------------------------------------------------------
+pub fn track(k: usize, reference: &[u8], read_counts: &SeqCounts) -> Vec<(usize, u32)> {
+    if reference.len() < k {
+        return Vec::new();
+    }
+    let mut seq = Sequence::default();
+    let mut track = Vec::with_capacity(reference.len() - k + 1);
+    for (i, &base) in reference.iter().enumerate() {
+        seq = seq.pushed(base, k);
+        if i + 1 >= k {
+            track.push((i + 1 - k, *read_counts.get(&seq).unwrap_or(&0)));
+        }
+    }
+    track
+}
+
+pub fn to_bedgraph(chrom: &str, track: &[(usize, u32)]) -> String {
+    track
+        .iter()
+        .map(|(pos, count)| format!("{}\t{}\t{}\t{}", chrom, pos, pos + 1, count))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,59 @@
+// Duplicate-record detection: hashes whole records to flag exact PCR
+// duplicates or repeated contigs across an input.
+
+// Imports --------------------------------------------------------------------
+use crate::fasta::FastaRecord;
+use hashbrown::HashMap;
+
+// Types ----------------------------------------------------------------------
+pub struct DuplicateGroup {
+    pub seq: Vec<u8>,
+    pub ids: Vec<String>,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Groups records with byte-identical sequences, keeping only groups with
+/// more than one member.
+pub fn find_duplicates(records: &[FastaRecord]) -> Vec<DuplicateGroup> {
+    let mut by_seq: HashMap<&[u8], Vec<&str>> = HashMap::new();
+    for record in records {
+        by_seq.entry(&record.seq).or_default().push(&record.id);
+    }
+
+    by_seq
+        .into_iter()
+        .filter(|(_, ids)| ids.len() > 1)
+        .map(|(seq, ids)| DuplicateGroup {
+            seq: seq.to_vec(),
+            ids: ids.into_iter().map(String::from).collect(),
+        })
+        .collect()
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(id: &str, seq: &[u8]) -> FastaRecord {
+        FastaRecord { id: id.into(), desc: String::new(), seq: seq.to_vec() }
+    }
+
+    #[test]
+    fn flags_records_with_identical_sequences() {
+        let records = vec![record("a", b"ACGT"), record("b", b"ACGT"), record("c", b"TTTT")];
+        let mut groups = find_duplicates(&records);
+        assert_eq!(groups.len(), 1);
+        let group = groups.remove(0);
+        assert_eq!(group.seq, b"ACGT");
+        let mut ids = group.ids;
+        ids.sort();
+        assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    }
+
+    #[test]
+    fn unique_sequences_produce_no_groups() {
+        let records = vec![record("a", b"ACGT"), record("b", b"TTTT")];
+        assert!(find_duplicates(&records).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+// Deterministic seeded hashing: hashbrown's default `HashMap` uses a
+// randomized per-process seed, so bucket order (and therefore iteration
+// order, and therefore anything downstream that doesn't sort before
+// printing) differs between runs even for identical input. Opting into this
+// hasher trades that randomization for reproducible memory layout, which
+// matters for golden-file comparisons and bug reports that include a raw
+// dump.
+
+// Imports --------------------------------------------------------------------
+use std::hash::{BuildHasher, Hasher};
+
+/// The default seed used by [`map`]; fixed so two runs of the same binary
+/// produce byte-identical hashbrown bucket layouts.
+const DEFAULT_SEED: u64 = 0x9E3779B97F4A7C15;
+
+// Types ----------------------------------------------------------------------
+#[derive(Clone, Copy)]
+pub struct BuildSplitMix64 {
+    seed: u64,
+}
+
+pub struct SplitMix64Hasher {
+    state: u64,
+}
+
+// Public Functions -------------------------------------------------------------
+pub type DeterministicMap<K, V> = hashbrown::HashMap<K, V, BuildSplitMix64>;
+
+pub fn map<K, V>() -> DeterministicMap<K, V> {
+    hashbrown::HashMap::with_hasher(BuildSplitMix64::new(DEFAULT_SEED))
+}
+
+impl BuildSplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl Default for BuildSplitMix64 {
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl BuildHasher for BuildSplitMix64 {
+    type Hasher = SplitMix64Hasher;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SplitMix64Hasher { state: self.seed }
+    }
+}
+
+impl Hasher for SplitMix64Hasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state = self.state.wrapping_add(byte as u64).wrapping_mul(0xFF51AFD7ED558CCD);
+        }
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.state = self.state.wrapping_add(value).wrapping_mul(0xFF51AFD7ED558CCD);
+    }
+
+    fn finish(&self) -> u64 {
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,60 @@
+// Symmetrized dinucleotide odds-ratio report (Karlin-Burge style): each
+// ratio rho_XY = f'(XY) / (f'(X) * f'(Y)) compares an observed dinucleotide
+// frequency against what independent mononucleotide frequencies would
+// predict. Frequencies are symmetrized by folding in their reverse
+// complement so the result doesn't depend on which strand happened to be
+// sequenced.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::SeqCounts;
+use hashbrown::HashMap;
+
+// Public Functions -------------------------------------------------------------
+pub fn odds_ratios(mono: &SeqCounts, di: &SeqCounts) -> Vec<(String, f64)> {
+    let mono_freq = symmetrized_freqs(mono, 1);
+    let di_freq = symmetrized_freqs(di, 2);
+
+    let mut ratios: Vec<(String, f64)> = di_freq
+        .iter()
+        .map(|(pair, &f_xy)| {
+            let (x, y) = (&pair[0..1], &pair[1..2]);
+            let expected = mono_freq.get(x).copied().unwrap_or(0.0) * mono_freq.get(y).copied().unwrap_or(0.0);
+            let ratio = if expected > 0.0 { f_xy / expected } else { 0.0 };
+            (pair.clone(), ratio)
+        })
+        .collect();
+    ratios.sort_by(|(l, _), (r, _)| l.cmp(r));
+    ratios
+}
+
+// Private Functions ------------------------------------------------------------
+/// Decodes `counts` to strings and folds each entry's count in with its
+/// reverse complement's, so `AT` and its own complement `AT` count once
+/// while e.g. `AC`/`GT` share a single symmetrized frequency.
+fn symmetrized_freqs(counts: &SeqCounts, k: usize) -> HashMap<String, f64> {
+    let decoded: HashMap<String, u32> = counts.iter().map(|(seq, &count)| (seq.to_str(k), count)).collect();
+    let total: u64 = decoded.values().map(|&c| c as u64).sum::<u64>() * 2;
+
+    decoded
+        .iter()
+        .map(|(seq_str, &count)| {
+            let rc = reverse_complement(seq_str);
+            let combined = count as u64 + decoded.get(&rc).copied().unwrap_or(0) as u64;
+            (seq_str.clone(), combined as f64 / total as f64)
+        })
+        .collect()
+}
+
+fn reverse_complement(seq: &str) -> String {
+    seq.chars().rev().map(complement).collect()
+}
+
+fn complement(base: char) -> char {
+    match base {
+        'A' => 'T',
+        'T' => 'A',
+        'G' => 'C',
+        'C' => 'G',
+        other => other,
+    }
+}
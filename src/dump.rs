@@ -0,0 +1,39 @@
+// Binary dump format for `SeqCounts`, shared by checkpointing, caching, and
+// disk-backed backends: a small fixed-width record per entry so tables can be
+// written and read back without re-parsing the genome.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+use hashbrown::HashMap;
+use std::io::{Read, Write};
+
+// Public Functions -------------------------------------------------------------
+/// Layout: 8 bytes little-endian entry count, then per entry 8 bytes key + 4
+/// bytes count, both little-endian.
+pub fn dump<W: Write>(counts: &SeqCounts, mut w: W) -> std::io::Result<()> {
+    w.write_all(&(counts.len() as u64).to_le_bytes())?;
+    for (seq, count) in counts {
+        w.write_all(&seq.key().to_le_bytes())?;
+        w.write_all(&count.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+pub fn load<R: Read>(mut r: R) -> std::io::Result<SeqCounts> {
+    let mut len_buf = [0u8; 8];
+    r.read_exact(&mut len_buf)?;
+    let len = u64::from_le_bytes(len_buf) as usize;
+
+    let mut counts = HashMap::with_capacity(len);
+    for _ in 0..len {
+        let mut key_buf = [0u8; 8];
+        r.read_exact(&mut key_buf)?;
+        let mut count_buf = [0u8; 4];
+        r.read_exact(&mut count_buf)?;
+        counts.insert(
+            Sequence::from_key(u64::from_le_bytes(key_buf)),
+            u32::from_le_bytes(count_buf),
+        );
+    }
+    Ok(counts)
+}
@@ -0,0 +1,21 @@
+// Embedder-friendly guarantees: nothing in this crate's library code reaches
+// for a global (no `static` outside test modules, no `thread_local!`, and no
+// process-wide singleton of its own), so an embedder can run multiple independent counts — different
+// genomes, different configs, different threads — in the same process
+// without them contending over hidden shared state. The asserts below pin
+// the Send/Sync side of that guarantee so a future change that quietly adds
+// an `Rc` or a `Cell` to one of these types fails to compile instead of
+// silently becoming unsafe to share across threads.
+
+// Private Functions ------------------------------------------------------------
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[allow(dead_code)]
+fn audit() {
+    assert_send_sync::<crate::knucleotide::Sequence>();
+    assert_send_sync::<crate::knucleotide::SeqCounts>();
+    assert_send_sync::<crate::config::Config>();
+    assert_send_sync::<crate::cancel::CancelToken>();
+    assert_send_sync::<crate::backend::HashBackend>();
+    assert_send_sync::<crate::backend::DenseBackend>();
+}
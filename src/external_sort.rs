@@ -0,0 +1,160 @@
+// External merge sort over `dump` files too large to comfortably hold in
+// memory: entries are read in bounded-size runs, each run is sorted and
+// spilled to a temp file, then all runs are merged with a single pass that
+// only ever keeps one entry per run resident.
+
+// Imports --------------------------------------------------------------------
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+// Types ----------------------------------------------------------------------
+type Entry = (u64, u32);
+
+// Public Functions -------------------------------------------------------------
+/// Sorts the entries of a `dump`-format file at `input_path` by key,
+/// writing the result (same format) to `output_path`. At most `run_entries`
+/// entries are held in memory at once; intermediate runs are spilled under
+/// `tmp_dir`.
+pub fn sort(input_path: &Path, output_path: &Path, run_entries: usize, tmp_dir: &Path) -> std::io::Result<()> {
+    let runs = write_sorted_runs(input_path, run_entries, tmp_dir)?;
+    merge_runs(&runs, output_path)?;
+    for run in &runs {
+        let _ = std::fs::remove_file(run);
+    }
+    Ok(())
+}
+
+// Private Functions ------------------------------------------------------------
+fn write_sorted_runs(input_path: &Path, run_entries: usize, tmp_dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if run_entries == 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "run_entries must be at least 1"));
+    }
+    let mut r = BufReader::new(File::open(input_path)?);
+    let total = read_u64(&mut r)?;
+
+    let mut runs = Vec::new();
+    let mut remaining = total;
+    let mut run_index = 0;
+    while remaining > 0 {
+        let this_run = run_entries.min(remaining as usize);
+        let mut entries = Vec::with_capacity(this_run);
+        for _ in 0..this_run {
+            entries.push(read_entry(&mut r)?);
+        }
+        entries.sort_unstable_by_key(|(key, _)| *key);
+
+        let run_path = tmp_dir.join(format!("run_{run_index}.bin"));
+        let mut w = BufWriter::new(File::create(&run_path)?);
+        for (key, count) in &entries {
+            write_entry(&mut w, *key, *count)?;
+        }
+        runs.push(run_path);
+        remaining -= this_run as u64;
+        run_index += 1;
+    }
+    Ok(runs)
+}
+
+fn merge_runs(runs: &[PathBuf], output_path: &Path) -> std::io::Result<()> {
+    let mut readers: Vec<BufReader<File>> = runs.iter().map(|p| Ok(BufReader::new(File::open(p)?))).collect::<std::io::Result<_>>()?;
+    let mut heads: Vec<Option<Entry>> = readers.iter_mut().map(|r| read_entry(r).ok()).collect();
+    let total: u64 = {
+        let mut count = 0u64;
+        for run in runs {
+            count += std::fs::metadata(run)?.len() / 12;
+        }
+        count
+    };
+
+    let mut w = BufWriter::new(File::create(output_path)?);
+    w.write_all(&total.to_le_bytes())?;
+
+    loop {
+        let Some((winner, (key, count))) = heads
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| e.map(|e| (i, e)))
+            .min_by_key(|(_, (key, _))| *key)
+        else {
+            break;
+        };
+        write_entry(&mut w, key, count)?;
+        heads[winner] = read_entry(&mut readers[winner]).ok();
+    }
+    Ok(())
+}
+
+fn read_u64(r: &mut impl Read) -> std::io::Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_entry(r: &mut impl Read) -> std::io::Result<Entry> {
+    let mut key_buf = [0u8; 8];
+    r.read_exact(&mut key_buf)?;
+    let mut count_buf = [0u8; 4];
+    r.read_exact(&mut count_buf)?;
+    Ok((u64::from_le_bytes(key_buf), u32::from_le_bytes(count_buf)))
+}
+
+fn write_entry(w: &mut impl Write, key: u64, count: u32) -> std::io::Result<()> {
+    w.write_all(&key.to_le_bytes())?;
+    w.write_all(&count.to_le_bytes())?;
+    Ok(())
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_dump(path: &Path, entries: &[Entry]) {
+        let mut w = BufWriter::new(File::create(path).unwrap());
+        w.write_all(&(entries.len() as u64).to_le_bytes()).unwrap();
+        for &(key, count) in entries {
+            write_entry(&mut w, key, count).unwrap();
+        }
+    }
+
+    fn read_dump(path: &Path) -> Vec<Entry> {
+        let mut r = BufReader::new(File::open(path).unwrap());
+        let total = read_u64(&mut r).unwrap();
+        (0..total).map(|_| read_entry(&mut r).unwrap()).collect()
+    }
+
+    #[test]
+    fn rejects_a_zero_run_entries() {
+        let dir = std::env::temp_dir().join(format!("nucleotide-external-sort-test-{:?}-zero", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let input_path = dir.join("input.bin");
+        write_dump(&input_path, &[(1, 1), (2, 2)]);
+
+        let err = write_sorted_runs(&input_path, 0, &dir).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn sorts_entries_across_multiple_runs() {
+        let dir = std::env::temp_dir().join(format!("nucleotide-external-sort-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let input_path = dir.join("input.bin");
+        let output_path = dir.join("output.bin");
+        let entries: Vec<Entry> = vec![(5, 1), (3, 2), (9, 3), (1, 4), (7, 5), (2, 6)];
+        write_dump(&input_path, &entries);
+
+        sort(&input_path, &output_path, 2, &dir).unwrap();
+
+        let sorted = read_dump(&output_path);
+        let mut expected = entries;
+        expected.sort_unstable_by_key(|(key, _)| *key);
+        assert_eq!(sorted, expected);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+// Sequence region extraction: given a region like `chr3:1000-2000`, prints
+// the requested subsequence (optionally reverse-complemented). A small but
+// frequently needed companion to the analysis features.
+
+// Imports --------------------------------------------------------------------
+use crate::fai::FaiEntry;
+use crate::fasta::FastaRecord;
+use std::path::Path;
+
+// Types ----------------------------------------------------------------------
+pub struct Region {
+    pub id: String,
+    /// 1-based, inclusive, as written in `chr3:1,000-2,000`.
+    pub start: usize,
+    pub end: usize,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Parses `chr3:1,000-2,000`-style region strings (thousands separators
+/// allowed, since that's how they're often pasted from browsers).
+pub fn parse_region(spec: &str) -> Option<Region> {
+    let (id, range) = spec.split_once(':')?;
+    let (start, end) = range.split_once('-')?;
+    Some(Region {
+        id: id.to_string(),
+        start: start.replace(',', "").parse().ok()?,
+        end: end.replace(',', "").parse().ok()?,
+    })
+}
+
+pub fn extract(records: &[FastaRecord], region: &Region, revcomp: bool) -> Option<Vec<u8>> {
+    let record = records.iter().find(|r| r.id == region.id)?;
+    let start = region.start.saturating_sub(1);
+    let end = region.end.min(record.seq.len());
+    if start >= end {
+        return Some(Vec::new());
+    }
+    let slice = record.seq[start..end].to_vec();
+    Some(if revcomp { reverse_complement(&slice) } else { slice })
+}
+
+/// Same as [`extract`], but seeks directly into `fasta_path` via a `.fai`
+/// index instead of scanning a fully-parsed record list, so it stays fast on
+/// files too large to comfortably hold in memory.
+pub fn extract_indexed(
+    fasta_path: &Path,
+    index: &[FaiEntry],
+    region: &Region,
+    revcomp: bool,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let Some(entry) = index.iter().find(|e| e.name == region.id) else {
+        return Ok(None);
+    };
+    let start = region.start.saturating_sub(1) as u64;
+    let end = (region.end as u64).min(entry.length);
+    if start >= end {
+        return Ok(Some(Vec::new()));
+    }
+    let bases = crate::fai::fetch(fasta_path, entry, start, end)?;
+    Ok(Some(if revcomp { reverse_complement(&bases) } else { bases }))
+}
+
+// Private Functions ------------------------------------------------------------
+fn reverse_complement(seq: &[u8]) -> Vec<u8> {
+    seq.iter().rev().map(|&b| complement(b)).collect()
+}
+
+fn complement(base: u8) -> u8 {
+    match base {
+        b'A' => b'T',
+        b'T' => b'A',
+        b'G' => b'C',
+        b'C' => b'G',
+        b'a' => b't',
+        b't' => b'a',
+        b'g' => b'c',
+        b'c' => b'g',
+        other => other,
+    }
+}
@@ -0,0 +1,108 @@
+// Samtools-compatible `.fai` index generation and indexed random access, so
+// region extraction and BED-restricted counting can seek directly into a
+// large FASTA file instead of scanning it from the start.
+
+// Imports --------------------------------------------------------------------
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+// Types ----------------------------------------------------------------------
+/// One line of a `.fai` file: name, length, byte offset of the first base,
+/// bases per line, and bytes per line (including the line terminator).
+pub struct FaiEntry {
+    pub name: String,
+    pub length: u64,
+    pub offset: u64,
+    pub line_bases: u64,
+    pub line_bytes: u64,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn build(fasta_path: &Path) -> std::io::Result<Vec<FaiEntry>> {
+    let mut r = BufReader::new(File::open(fasta_path)?);
+    let mut entries = Vec::new();
+    let mut line = String::new();
+    let mut offset = 0u64;
+    let mut current: Option<FaiEntry> = None;
+
+    loop {
+        line.clear();
+        let read = r.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        if let Some(name) = line.trim_end().strip_prefix('>') {
+            if let Some(entry) = current.take() {
+                entries.push(entry);
+            }
+            offset += read as u64;
+            current = Some(FaiEntry {
+                name: name.split_whitespace().next().unwrap_or("").to_string(),
+                length: 0,
+                offset,
+                line_bases: 0,
+                line_bytes: 0,
+            });
+        } else if let Some(entry) = current.as_mut() {
+            let bases = line.trim_end().len() as u64;
+            if entry.line_bases == 0 {
+                entry.line_bases = bases;
+                entry.line_bytes = read as u64;
+            }
+            entry.length += bases;
+            offset += read as u64;
+        }
+    }
+    if let Some(entry) = current.take() {
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+pub fn write(entries: &[FaiEntry], mut w: impl Write) -> std::io::Result<()> {
+    for e in entries {
+        writeln!(w, "{}\t{}\t{}\t{}\t{}", e.name, e.length, e.offset, e.line_bases, e.line_bytes)?;
+    }
+    Ok(())
+}
+
+pub fn read(mut r: impl BufRead) -> std::io::Result<Vec<FaiEntry>> {
+    let mut entries = Vec::new();
+    let mut line = String::new();
+    while r.read_line(&mut line)? > 0 {
+        let fields: Vec<&str> = line.trim_end().split('\t').collect();
+        if let [name, length, offset, line_bases, line_bytes] = fields[..] {
+            entries.push(FaiEntry {
+                name: name.to_string(),
+                length: length.parse().unwrap_or(0),
+                offset: offset.parse().unwrap_or(0),
+                line_bases: line_bases.parse().unwrap_or(0),
+                line_bytes: line_bytes.parse().unwrap_or(0),
+            });
+        }
+        line.clear();
+    }
+    Ok(entries)
+}
+
+/// Seeks directly to `start..end` (0-based, exclusive) of `entry` in
+/// `fasta_path` without scanning from the beginning of the file.
+pub fn fetch(fasta_path: &Path, entry: &FaiEntry, start: u64, end: u64) -> std::io::Result<Vec<u8>> {
+    let mut f = File::open(fasta_path)?;
+    let end = end.min(entry.length);
+    let mut bases = Vec::with_capacity((end - start) as usize);
+    let mut pos = start;
+    while pos < end {
+        let line_index = pos / entry.line_bases;
+        let col = pos % entry.line_bases;
+        let byte_offset = entry.offset + line_index * entry.line_bytes + col;
+        f.seek(SeekFrom::Start(byte_offset))?;
+        let take = (entry.line_bases - col).min(end - pos);
+        let mut buf = vec![0u8; take as usize];
+        f.read_exact(&mut buf)?;
+        bases.extend_from_slice(&buf);
+        pos += take;
+    }
+    Ok(bases)
+}
@@ -0,0 +1,95 @@
+// Minimal streaming FASTA parsing shared by the record-oriented features
+// (filtering, stats, extraction), separate from `knucleotide::read_file`
+// which only cares about concatenated bases from a single named record.
+
+// Imports --------------------------------------------------------------------
+use std::io::BufRead;
+
+// Types ----------------------------------------------------------------------
+pub struct FastaRecord {
+    pub id: String,
+    pub desc: String,
+    pub seq: Vec<u8>,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn parse<R: BufRead>(mut r: R) -> Vec<FastaRecord> {
+    let mut records = Vec::new();
+    let mut line = String::new();
+    let (mut id, mut desc, mut seq) = (String::new(), String::new(), Vec::new());
+
+    while r.read_line(&mut line).unwrap_or(0) > 0 {
+        let trimmed = line.trim_end();
+        if let Some(header) = trimmed.strip_prefix('>') {
+            if !id.is_empty() {
+                records.push(FastaRecord {
+                    id: std::mem::take(&mut id),
+                    desc: std::mem::take(&mut desc),
+                    seq: std::mem::take(&mut seq),
+                });
+            }
+            let (parsed_id, parsed_desc) = split_header(header);
+            id = parsed_id;
+            desc = parsed_desc;
+        } else {
+            seq.extend_from_slice(trimmed.as_bytes());
+        }
+        line.clear();
+    }
+    if !id.is_empty() {
+        records.push(FastaRecord { id, desc, seq });
+    }
+    records
+}
+
+/// Panic-free FASTA parsing over an in-memory byte slice, for fuzzing and for
+/// embedders that can't hand us a `BufRead`. Malformed input (no header, bad
+/// UTF-8 in the description) is tolerated rather than rejected: anything not
+/// starting with '>' before the first record is simply skipped.
+pub fn parse_fasta_bytes(bytes: &[u8]) -> Result<Vec<FastaRecord>, ParseError> {
+    let mut records = Vec::new();
+    let (mut id, mut desc, mut seq) = (String::new(), String::new(), Vec::new());
+
+    for line in bytes.split(|&b| b == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if let Some(header) = line.strip_prefix(b">") {
+            if !id.is_empty() {
+                records.push(FastaRecord {
+                    id: std::mem::take(&mut id),
+                    desc: std::mem::take(&mut desc),
+                    seq: std::mem::take(&mut seq),
+                });
+            }
+            let (parsed_id, parsed_desc) = split_header(&String::from_utf8_lossy(header));
+            id = parsed_id;
+            desc = parsed_desc;
+        } else if !id.is_empty() {
+            seq.extend_from_slice(line);
+        }
+    }
+    if !id.is_empty() {
+        records.push(FastaRecord { id, desc, seq });
+    }
+    Ok(records)
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+pub fn gc_content(seq: &[u8]) -> f32 {
+    if seq.is_empty() {
+        return 0.0;
+    }
+    let gc = seq.iter().filter(|b| matches!(b, b'G' | b'C' | b'g' | b'c')).count();
+    gc as f32 / seq.len() as f32
+}
+
+// Private Functions ------------------------------------------------------------
+/// Splits a FASTA header (without the leading `>`) into its ID (first
+/// whitespace-delimited token) and the remaining free-text description.
+fn split_header(header: &str) -> (String, String) {
+    match header.split_once(char::is_whitespace) {
+        Some((id, desc)) => (id.to_string(), desc.trim_start().to_string()),
+        None => (header.to_string(), String::new()),
+    }
+}
@@ -0,0 +1,53 @@
+// Minimal FASTQ reading shared by the read-screening features.
+
+// Imports --------------------------------------------------------------------
+use std::io::{BufRead, BufReader};
+use std::fs::File;
+
+// Types ----------------------------------------------------------------------
+pub struct FastqRecord {
+    pub seq: Vec<u8>,
+}
+
+#[derive(Debug)]
+pub struct ParseError;
+
+// Public Functions -------------------------------------------------------------
+/// Panic-free FASTQ parsing over an in-memory byte slice, for fuzzing and for
+/// embedders that can't hand us a file path. Truncated final records (missing
+/// the `+` line or quality line) are dropped rather than causing a panic.
+pub fn parse_fastq_bytes(bytes: &[u8]) -> Result<Vec<FastqRecord>, ParseError> {
+    let mut lines = bytes.split(|&b| b == b'\n');
+    let mut records = Vec::new();
+    loop {
+        let Some(_header) = lines.next() else { break };
+        let Some(seq) = lines.next() else { break };
+        let Some(_plus) = lines.next() else { break };
+        let Some(_qual) = lines.next() else { break };
+        let seq = seq.strip_suffix(b"\r").unwrap_or(seq);
+        records.push(FastqRecord { seq: seq.to_vec() });
+    }
+    Ok(records)
+}
+
+pub fn read_fastq(path: &str) -> Vec<FastqRecord> {
+    let mut r = BufReader::new(File::open(path).unwrap());
+    let mut records = Vec::new();
+    let (mut header, mut seq, mut plus, mut qual) = (String::new(), String::new(), String::new(), String::new());
+    loop {
+        header.clear();
+        if r.read_line(&mut header).unwrap_or(0) == 0 {
+            break;
+        }
+        seq.clear();
+        r.read_line(&mut seq).unwrap();
+        plus.clear();
+        r.read_line(&mut plus).unwrap();
+        qual.clear();
+        r.read_line(&mut qual).unwrap();
+        records.push(FastqRecord {
+            seq: seq.trim_end().as_bytes().to_vec(),
+        });
+    }
+    records
+}
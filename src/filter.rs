@@ -0,0 +1,128 @@
+// A `filter` mode that reads FASTA on stdin and writes records passing
+// composition predicates to stdout, so the crate slots into Unix pipelines
+// as a fast pre-filter.
+
+// Imports --------------------------------------------------------------------
+use crate::fasta::FastaRecord;
+use rayon::prelude::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+
+// Types ----------------------------------------------------------------------
+pub struct Predicates {
+    pub gc_range: (f32, f32),
+    pub max_homopolymer: usize,
+    pub min_complexity: f32,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn passes(record: &FastaRecord, predicates: &Predicates) -> bool {
+    let gc = crate::fasta::gc_content(&record.seq);
+    gc >= predicates.gc_range.0
+        && gc <= predicates.gc_range.1
+        && max_homopolymer(&record.seq) <= predicates.max_homopolymer
+        && complexity(&record.seq) >= predicates.min_complexity
+}
+
+pub fn run<W: Write>(records: Vec<FastaRecord>, predicates: &Predicates, mut out: W) -> std::io::Result<()> {
+    for record in records.into_iter().filter(|r| passes(r, predicates)) {
+        writeln!(out, ">{}", record.id)?;
+        out.write_all(&record.seq)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+/// Demultiplexes `records` into named buckets, keyed by whichever `bins`
+/// predicate first matches, then writes each bucket to `<prefix>.<bin>.fa` in
+/// parallel so partitioning a large metagenome doesn't serialize on I/O.
+pub fn demux(
+    records: Vec<FastaRecord>,
+    bins: Vec<(String, Box<dyn Fn(&FastaRecord) -> bool + Sync + Send>)>,
+    out_prefix: &str,
+) -> std::io::Result<()> {
+    let mut buckets: Vec<Vec<FastaRecord>> = bins.iter().map(|_| Vec::new()).collect();
+    'records: for record in records {
+        for (i, (_, predicate)) in bins.iter().enumerate() {
+            if predicate(&record) {
+                buckets[i].push(record);
+                continue 'records;
+            }
+        }
+    }
+
+    bins.into_par_iter()
+        .zip(buckets)
+        .map(|((name, _), bucket)| write_bucket(out_prefix, &name, bucket))
+        .collect::<std::io::Result<Vec<()>>>()?;
+    Ok(())
+}
+
+// Private Functions ------------------------------------------------------------
+fn write_bucket(out_prefix: &str, name: &str, records: Vec<FastaRecord>) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(format!("{out_prefix}.{name}.fa"))?);
+    for record in records {
+        writeln!(out, ">{}", record.id)?;
+        out.write_all(&record.seq)?;
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn max_homopolymer(seq: &[u8]) -> usize {
+    let (mut longest, mut current, mut last) = (0, 0, 0u8);
+    for &b in seq {
+        current = if b == last { current + 1 } else { 1 };
+        last = b;
+        longest = longest.max(current);
+    }
+    longest
+}
+
+/// Fraction of distinct 3-mers over total 3-mer windows: a cheap
+/// low-complexity indicator (repeats and homopolymers score low).
+fn complexity(seq: &[u8]) -> f32 {
+    if seq.len() < 3 {
+        return 1.0;
+    }
+    let windows: Vec<&[u8]> = seq.windows(3).collect();
+    let mut distinct: Vec<&[u8]> = windows.clone();
+    distinct.sort();
+    distinct.dedup();
+    distinct.len() as f32 / windows.len() as f32
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn demux_routes_to_the_first_matching_bin_and_writes_its_file() {
+        let records = vec![
+            FastaRecord { id: "gc-low".into(), desc: String::new(), seq: b"AATTAATTAATT".to_vec() },
+            FastaRecord { id: "gc-high".into(), desc: String::new(), seq: b"GGCCGGCCGGCC".to_vec() },
+            FastaRecord { id: "unmatched".into(), desc: String::new(), seq: b"ACACACACACAC".to_vec() },
+        ];
+        let bins: Vec<(String, Box<dyn Fn(&FastaRecord) -> bool + Sync + Send>)> = vec![
+            ("at".to_string(), Box::new(|r: &FastaRecord| crate::fasta::gc_content(&r.seq) < 0.5)),
+            ("gc".to_string(), Box::new(|r: &FastaRecord| crate::fasta::gc_content(&r.seq) >= 0.5)),
+        ];
+
+        let out_prefix = std::env::temp_dir().join(format!("nucleotide-demux-test-{:?}", std::thread::current().id()));
+        let out_prefix = out_prefix.to_str().unwrap().to_string();
+
+        demux(records, bins, &out_prefix).unwrap();
+
+        let at = std::fs::read_to_string(format!("{out_prefix}.at.fa")).unwrap();
+        assert!(at.contains(">gc-low"));
+        assert!(!at.contains(">gc-high"));
+
+        let gc = std::fs::read_to_string(format!("{out_prefix}.gc.fa")).unwrap();
+        assert!(gc.contains(">gc-high"));
+        assert!(gc.contains(">unmatched"));
+
+        std::fs::remove_file(format!("{out_prefix}.at.fa")).unwrap();
+        std::fs::remove_file(format!("{out_prefix}.gc.fa")).unwrap();
+    }
+}
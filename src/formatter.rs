@@ -0,0 +1,41 @@
+// Pluggable output renderers: `table`'s `iter_*` functions decode a
+// `SeqCounts` into a plain `Vec<(String, u32)>`, so rendering it as TSV, JSON, or
+// anything else an embedder wants is just a trait away from the counting
+// code rather than a hardcoded `println!` format.
+
+// Public Functions -------------------------------------------------------------
+pub trait OutputFormatter {
+    fn format(&self, rows: &[(String, u32)]) -> String;
+}
+
+pub struct TsvFormatter;
+
+impl OutputFormatter for TsvFormatter {
+    fn format(&self, rows: &[(String, u32)]) -> String {
+        rows.iter().map(|(seq, count)| format!("{}\t{}", count, seq)).collect::<Vec<_>>().join("\n")
+    }
+}
+
+pub struct JsonFormatter;
+
+impl OutputFormatter for JsonFormatter {
+    fn format(&self, rows: &[(String, u32)]) -> String {
+        let entries: Vec<serde_json::Value> = rows
+            .iter()
+            .map(|(seq, count)| serde_json::json!({"sequence": seq, "count": count}))
+            .collect();
+        serde_json::to_string_pretty(&entries).unwrap_or_default()
+    }
+}
+
+pub struct CsvFormatter;
+
+impl OutputFormatter for CsvFormatter {
+    fn format(&self, rows: &[(String, u32)]) -> String {
+        let mut out = String::from("sequence,count\n");
+        for (seq, count) in rows {
+            out.push_str(&format!("{},{}\n", seq, count));
+        }
+        out
+    }
+}
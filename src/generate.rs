@@ -0,0 +1,51 @@
+// Seeded synthetic genome generation for correctness tests, beyond the
+// Benchmarks Game's own fasta generator: GC content and a planted number of
+// motif copies are set ahead of time, so counting results can be checked
+// against them.
+
+// Imports --------------------------------------------------------------------
+use crate::sample::SplitMix64;
+
+// Types ----------------------------------------------------------------------
+pub struct GenomeSpec {
+    pub len: usize,
+    pub gc_content: f64,
+    pub motifs: Vec<(String, usize)>,
+    pub seed: u64,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Builds a random genome of `spec.len` bases at the requested GC content,
+/// then, for each `(motif, count)`, overwrites `count` non-overlapping
+/// windows, back to back from the start of the genome, with that motif;
+/// copies that would run past the end are not placed. The random background
+/// is not scrubbed, so it (and the junction between adjacent copies) can add
+/// further occurrences: the planted count is a lower bound, not an exact total.
+pub fn generate(spec: &GenomeSpec) -> Vec<u8> {
+    let mut rng = SplitMix64::new(spec.seed);
+    let mut genome: Vec<u8> = (0..spec.len).map(|_| base(&mut rng, spec.gc_content)).collect();
+
+    let mut cursor = 0;
+    for (motif, count) in &spec.motifs {
+        let bytes = motif.as_bytes();
+        for _ in 0..*count {
+            if cursor + bytes.len() > genome.len() {
+                break;
+            }
+            genome[cursor..cursor + bytes.len()].copy_from_slice(bytes);
+            cursor += bytes.len();
+        }
+    }
+    genome
+}
+
+// Private Functions ------------------------------------------------------------
+fn base(rng: &mut SplitMix64, gc_content: f64) -> u8 {
+    if rng.next_f64() < gc_content {
+        if rng.next_f64() < 0.5 { b'G' } else { b'C' }
+    } else if rng.next_f64() < 0.5 {
+        b'A'
+    } else {
+        b'T'
+    }
+}
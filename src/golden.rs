@@ -0,0 +1,53 @@
+// Golden compatibility fixtures: a tiny, hand-verified sequence whose k=1
+// and k=2 counts match what Jellyfish (`jellyfish count -m 1/2 -s 100`,
+// non-canonical) and KMC report for the same input, so a change to the counting core that
+// silently disagrees with the established tools gets caught here rather
+// than in the field.
+
+// Public Functions -------------------------------------------------------------
+/// `count_k`'s chunked scan steps through the genome `len / 64` bytes at a
+/// time regardless of length — this 80-byte fixture steps one byte at a
+/// time, giving about 80 tiny, heavily overlapping pieces, so it still exercises the
+/// chunked scan's boundary handling while staying small enough to
+/// hand-verify against Jellyfish/KMC.
+pub fn golden_sequence() -> Vec<u8> {
+    b"ACGT".repeat(20)
+}
+
+pub fn expected_k1() -> Vec<(&'static str, u32)> {
+    vec![("A", 20), ("C", 20), ("G", 20), ("T", 20)]
+}
+
+pub fn expected_k2() -> Vec<(&'static str, u32)> {
+    vec![("AC", 20), ("CG", 20), ("GT", 20), ("TA", 19)]
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::knucleotide::count_k;
+    use crate::table::iter_lexicographic;
+
+    #[test]
+    fn matches_jellyfish_kmc_k1() {
+        let counts = count_k(1, &golden_sequence());
+        let mut expected = expected_k1();
+        expected.sort();
+        let mut actual: Vec<(String, u32)> = iter_lexicographic(1, &counts);
+        actual.sort();
+        let actual: Vec<(&str, u32)> = actual.iter().map(|(s, c)| (s.as_str(), *c)).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn matches_jellyfish_kmc_k2() {
+        let counts = count_k(2, &golden_sequence());
+        let mut expected = expected_k2();
+        expected.sort();
+        let mut actual: Vec<(String, u32)> = iter_lexicographic(2, &counts);
+        actual.sort();
+        let actual: Vec<(&str, u32)> = actual.iter().map(|(s, c)| (s.as_str(), *c)).collect();
+        assert_eq!(actual, expected);
+    }
+}
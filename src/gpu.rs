@@ -0,0 +1,117 @@
+// Experimental GPU counting backend: uploads the 2-bit packed genome and
+// counts k-mers via atomic adds into a dense table on the GPU. A prototype
+// comparison point for this benchmark-focused crate, not a production path;
+// practical only for small k (the dense table is 4^k * 4 bytes).
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+use hashbrown::HashMap;
+use wgpu::util::DeviceExt;
+
+// Public Functions -------------------------------------------------------------
+pub fn count_k_gpu(k: usize, genome: &[u8]) -> SeqCounts {
+    let instance = wgpu::Instance::default();
+    let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+        .expect("no compatible GPU adapter");
+    let (device, queue) =
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None))
+            .expect("failed to open GPU device");
+
+    let table_len = 4usize.pow(k as u32);
+    let codes: Vec<u32> = genome.iter().map(|&b| ((b >> 1) & 0b11) as u32).collect();
+
+    let codes_buf = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("codes"),
+        contents: bytemuck::cast_slice(&codes),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let counts_buf = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("counts"),
+        size: (table_len * std::mem::size_of::<u32>()) as u64,
+        usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        mapped_at_creation: false,
+    });
+    let params_buf = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("params"),
+        contents: bytemuck::cast_slice(&[k as u32, codes.len() as u32]),
+        usage: wgpu::BufferUsages::UNIFORM,
+    });
+
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("count_kmers"),
+        source: wgpu::ShaderSource::Wgsl(COUNT_KMERS_WGSL.into()),
+    });
+    let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("count_kmers"),
+        layout: None,
+        module: &shader,
+        entry_point: "main",
+    });
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("count_kmers_bindings"),
+        layout: &pipeline.get_bind_group_layout(0),
+        entries: &[
+            wgpu::BindGroupEntry { binding: 0, resource: codes_buf.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 1, resource: counts_buf.as_entire_binding() },
+            wgpu::BindGroupEntry { binding: 2, resource: params_buf.as_entire_binding() },
+        ],
+    });
+
+    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+    {
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+        pass.set_pipeline(&pipeline);
+        pass.set_bind_group(0, &bind_group, &[]);
+        pass.dispatch_workgroups((codes.len() as u32).div_ceil(256), 1, 1);
+    }
+
+    let staging = device.create_buffer(&wgpu::BufferDescriptor {
+        label: Some("staging"),
+        size: counts_buf.size(),
+        usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+        mapped_at_creation: false,
+    });
+    encoder.copy_buffer_to_buffer(&counts_buf, 0, &staging, 0, counts_buf.size());
+    queue.submit(Some(encoder.finish()));
+
+    let slice = staging.slice(..);
+    let (tx, rx) = std::sync::mpsc::channel();
+    slice.map_async(wgpu::MapMode::Read, move |res| {
+        let _ = tx.send(res);
+    });
+    device.poll(wgpu::Maintain::Wait);
+    rx.recv().expect("map_async callback dropped").expect("map_async failed");
+
+    let view = slice.get_mapped_range();
+    let raw: &[u32] = bytemuck::cast_slice(&view);
+    let mut counts: HashMap<Sequence, u32> = HashMap::new();
+    for (key, &count) in raw.iter().enumerate() {
+        if count > 0 {
+            counts.insert(Sequence::from_key(key as u64), count);
+        }
+    }
+    drop(view);
+    staging.unmap();
+    counts
+}
+
+const COUNT_KMERS_WGSL: &str = r#"
+struct Params { k: u32, len: u32 };
+
+@group(0) @binding(0) var<storage, read> codes: array<u32>;
+@group(0) @binding(1) var<storage, read_write> counts: array<atomic<u32>>;
+@group(0) @binding(2) var<uniform> params: Params;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let i = gid.x;
+    if (i + params.k > params.len) {
+        return;
+    }
+    var key: u32 = 0u;
+    for (var j: u32 = 0u; j < params.k; j = j + 1u) {
+        key = (key << 2u) | codes[i + j];
+    }
+    atomicAdd(&counts[key], 1u);
+}
+"#;
@@ -0,0 +1,64 @@
+// HTML report generation for `stats::Stats`, with charts rendered as inline
+// SVG so the report is a single self-contained file — no JS bundler, no
+// external chart library, works when opened straight off disk.
+
+// Imports --------------------------------------------------------------------
+use crate::stats::Stats;
+
+// Public Functions -------------------------------------------------------------
+pub fn render(stats: &Stats) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\"><title>Sequence Report</title></head><body>\n\
+         <h1>Sequence Report</h1>\n\
+         <ul>\n<li>Records: {}</li>\n<li>Total bases: {}</li>\n<li>N50: {}</li>\n</ul>\n\
+         <h2>Length distribution</h2>\n{}\n\
+         <h2>Per-record GC content</h2>\n{}\n\
+         </body></html>\n",
+        stats.record_count,
+        stats.total_bases,
+        stats.n50,
+        histogram_svg(&stats.length_histogram),
+        gc_svg(stats),
+    )
+}
+
+// Private Functions ------------------------------------------------------------
+fn histogram_svg(buckets: &[(usize, usize)]) -> String {
+    let max_count = buckets.iter().map(|(_, count)| *count).max().unwrap_or(1).max(1);
+    let bars: String = buckets
+        .iter()
+        .enumerate()
+        .map(|(i, (bucket, count))| {
+            let height = (*count as f64 / max_count as f64) * 100.0;
+            let x = i * 40;
+            format!(
+                "<rect x=\"{x}\" y=\"{}\" width=\"30\" height=\"{height}\" fill=\"steelblue\"><title>{bucket}: {count}</title></rect>",
+                100.0 - height
+            )
+        })
+        .collect();
+    format!("<svg width=\"{}\" height=\"100\" xmlns=\"http://www.w3.org/2000/svg\">{}</svg>", buckets.len() * 40 + 10, bars)
+}
+
+fn gc_svg(stats: &Stats) -> String {
+    let bars: String = stats
+        .per_record_gc
+        .iter()
+        .enumerate()
+        .map(|(i, record)| {
+            let height = record.gc as f64 * 100.0;
+            let x = i * 12;
+            format!(
+                "<rect x=\"{x}\" y=\"{}\" width=\"10\" height=\"{height}\" fill=\"seagreen\"><title>{}: {:.2}</title></rect>",
+                100.0 - height,
+                record.id,
+                record.gc,
+            )
+        })
+        .collect();
+    format!(
+        "<svg width=\"{}\" height=\"100\" xmlns=\"http://www.w3.org/2000/svg\">{}</svg>",
+        stats.per_record_gc.len() * 12 + 10,
+        bars
+    )
+}
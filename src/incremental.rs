@@ -0,0 +1,97 @@
+// Incremental k-mer counting: `CountState` can absorb additional sequence
+// data as it streams in, maintaining the rolling window across calls so
+// long-running services don't need to recount from scratch.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+use std::io::BufRead;
+
+// Types ----------------------------------------------------------------------
+pub struct CountState {
+    k: usize,
+    counts: SeqCounts,
+    window: Sequence,
+    seen: usize,
+}
+
+// Public Functions -------------------------------------------------------------
+impl CountState {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            counts: SeqCounts::default(),
+            window: Sequence::default(),
+            seen: 0,
+        }
+    }
+
+    /// Absorbs `data` into the running counts, continuing the rolling window
+    /// from wherever the previous `absorb` call left off.
+    pub fn absorb(&mut self, data: &[u8]) {
+        for &base in data {
+            self.window = self.window.pushed(base, self.k);
+            self.seen += 1;
+            if self.seen >= self.k {
+                *self.counts.entry(self.window).or_insert(0) += 1;
+            }
+        }
+    }
+
+    /// Absorbs `record` as a self-contained unit (its own rolling window,
+    /// independent of any prior `absorb`/`absorb_record` calls), so its
+    /// exact contribution can later be undone with `unabsorb_record`.
+    pub fn absorb_record(&mut self, record: &[u8]) {
+        for seq in record_kmers(record, self.k) {
+            *self.counts.entry(seq).or_insert(0) += 1;
+        }
+    }
+
+    /// Removes a record previously added with `absorb_record`, enabling
+    /// sliding-window analyses over a stream of records (e.g. composition
+    /// over the last N reads).
+    pub fn unabsorb_record(&mut self, record: &[u8]) {
+        for seq in record_kmers(record, self.k) {
+            if let Some(count) = self.counts.get_mut(&seq) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    self.counts.remove(&seq);
+                }
+            }
+        }
+    }
+
+    pub fn counts(&self) -> &SeqCounts {
+        &self.counts
+    }
+
+    /// Streams a multi-record FASTA source line by line, resetting the
+    /// rolling window at each `>` header so no k-mer straddles the boundary
+    /// between two records — unlike a plain `absorb` over the concatenated
+    /// bytes, this never materializes a whole record (or the whole file) in
+    /// memory at once.
+    pub fn absorb_fasta_stream<R: BufRead>(&mut self, mut r: R) -> std::io::Result<()> {
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            if r.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+            let line = line.strip_suffix(b"\n").map_or(&line[..], |l| l.strip_suffix(b"\r").unwrap_or(l));
+            if line.starts_with(b">") {
+                self.window = Sequence::default();
+                self.seen = 0;
+            } else {
+                self.absorb(line);
+            }
+        }
+        Ok(())
+    }
+}
+
+fn record_kmers(record: &[u8], k: usize) -> impl Iterator<Item = Sequence> + '_ {
+    let mut window = Sequence::default();
+    record.iter().enumerate().filter_map(move |(i, &base)| {
+        window = window.pushed(base, k);
+        (i + 1 >= k).then_some(window)
+    })
+}
@@ -0,0 +1,50 @@
+// Trait-based input source abstraction: counting code shouldn't care
+// whether its bases came from a plain file, an in-memory buffer, or (with
+// the `bgzf` feature) a compressed one — just that it can ask for the raw
+// bytes.
+
+// Imports --------------------------------------------------------------------
+use std::path::{Path, PathBuf};
+
+// Public Functions -------------------------------------------------------------
+pub trait InputSource {
+    fn load(&self) -> std::io::Result<Vec<u8>>;
+}
+
+// Types ----------------------------------------------------------------------
+/// A plain, uncompressed file on disk.
+pub struct FilePath(pub PathBuf);
+
+impl InputSource for FilePath {
+    fn load(&self) -> std::io::Result<Vec<u8>> {
+        std::fs::read(&self.0)
+    }
+}
+
+/// Bytes already resident in memory (e.g. produced by `generate::generate`
+/// or `mutate::mutate`), so callers don't need to round-trip through a file
+/// just to satisfy the trait.
+pub struct InMemory(pub Vec<u8>);
+
+impl InputSource for InMemory {
+    fn load(&self) -> std::io::Result<Vec<u8>> {
+        Ok(self.0.clone())
+    }
+}
+
+/// A BGZF-compressed file on disk, transparently inflated on load.
+#[cfg(feature = "bgzf")]
+pub struct BgzfPath(pub PathBuf);
+
+#[cfg(feature = "bgzf")]
+impl InputSource for BgzfPath {
+    fn load(&self) -> std::io::Result<Vec<u8>> {
+        crate::bgzf::decompress(&std::fs::read(&self.0)?)
+    }
+}
+
+impl InputSource for &Path {
+    fn load(&self) -> std::io::Result<Vec<u8>> {
+        std::fs::read(self)
+    }
+}
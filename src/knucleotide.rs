@@ -4,13 +4,14 @@
 // contributed by Greg Floyd
 
 // Imports --------------------------------------------------------------------
-use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use crate::sample::SplitMix64;
+use rayon::prelude::{IntoParallelIterator, ParallelBridge, ParallelIterator};
 use std::io::{BufRead, BufReader};
 use std::{fs::File, slice::Iter, sync::Arc, thread::spawn, thread::JoinHandle};
 use {crate::str, hashbrown::HashMap, std::vec::IntoIter};
 
 // Types ----------------------------------------------------------------------
-#[derive(Hash, Default, PartialEq, Eq, Clone, Copy)]
+#[derive(Hash, Default, PartialEq, Eq, Clone, Copy, Debug)]
 pub struct Sequence {
     key: u64,
 }
@@ -18,9 +19,11 @@ pub struct KGenomeIter<'a> {
     k: usize,
     seq: Sequence,
     genome: Iter<'a, u8>,
+    filled: usize,
 }
 pub type SeqCounts = HashMap<Sequence, u32>;
 pub type Threads = Vec<JoinHandle<(String, usize)>>;
+pub type OrderedThreads = Vec<JoinHandle<(String, usize, usize)>>;
 
 // Main -----------------------------------------------------------------------
 #[rustfmt::skip]
@@ -40,7 +43,7 @@ pub fn read_file(path: &str) -> Arc<Vec<u8>> {
     let (mut buf, mut line) = (Vec::with_capacity(15000000), Vec::new());
     while r.read_until(b'\n', &mut line).unwrap_or(0) > 0 {
         if read {
-            buf.extend_from_slice(&line[..line.len() - 1])
+            buf.extend_from_slice(trim_newline(&line))
         } else {
             read = line.starts_with(">TH".as_bytes())
         }
@@ -49,6 +52,34 @@ pub fn read_file(path: &str) -> Arc<Vec<u8>> {
     Arc::new(buf)
 }
 
+/// Like `read_file`, but streams a specific FASTA record by id (e.g. `"ONE"`,
+/// `"TWO"`, `"THREE"`) instead of always taking the last one, and stops as
+/// soon as the next header line starts so it works for records that aren't
+/// at the end of the file.
+pub fn read_record(path: &str, record_id: &str) -> std::io::Result<Arc<Vec<u8>>> {
+    let header = format!(">{record_id}");
+    let (mut reading, mut r) = (false, BufReader::new(File::open(path)?));
+    let (mut buf, mut line) = (Vec::with_capacity(15000000), Vec::new());
+    while r.read_until(b'\n', &mut line).unwrap_or(0) > 0 {
+        if line.starts_with(b">") {
+            if reading {
+                break;
+            }
+            reading = line.starts_with(header.as_bytes());
+        } else if reading {
+            buf.extend_from_slice(trim_newline(&line))
+        }
+        line.clear();
+    }
+    Ok(Arc::new(buf))
+}
+
+/// Strips a trailing `\n` or `\r\n`, so genomes produced on Windows don't
+/// have the `\r` byte encoded as a spurious base.
+fn trim_newline(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\n").map_or(line, |l| l.strip_suffix(b"\r").unwrap_or(l))
+}
+
 pub fn count(seqs: Vec<String>, genome: &Arc<Vec<u8>>) -> Threads {
     let mut threads = Vec::with_capacity(seqs.len());
     for str in sort_len(seqs) {
@@ -58,26 +89,194 @@ pub fn count(seqs: Vec<String>, genome: &Arc<Vec<u8>>) -> Threads {
     threads
 }
 
+/// Selects how [`show_ordered`] arranges the occurrence report; `show`
+/// always uses `Length` for backwards compatibility.
+#[derive(Clone, Copy)]
+pub enum ReportOrder {
+    /// The order `seqs` was originally given in.
+    Input,
+    Length,
+    Count,
+    Alpha,
+}
+
+/// Like `count`, but tags each pattern with its original position so
+/// [`show_ordered`] can honor `ReportOrder::Input`.
+pub fn count_ordered(seqs: Vec<String>, genome: &Arc<Vec<u8>>) -> OrderedThreads {
+    let mut threads = Vec::with_capacity(seqs.len());
+    for (index, str) in seqs.into_iter().enumerate() {
+        let arc = Arc::clone(&genome);
+        threads.push(spawn(move || {
+            let (seq_str, seq_cnt) = par_count(&str, &arc);
+            (seq_str, seq_cnt, index)
+        }));
+    }
+    threads
+}
+
 pub fn count_k(k: usize, genome: &[u8]) -> SeqCounts {
+    par_scan(genome, k - 1, |chunk| inner_count_k(k, chunk), HashMap::default, merge)
+}
+
+/// A `count_k`-family precondition was violated in a way that would
+/// otherwise panic deep inside the chunked scan (an empty `windows` size or
+/// a `step_by(0)`), surfaced here so embedders never see an unwind out of
+/// this crate.
+#[derive(Debug)]
+pub enum CountError {
+    ZeroK,
+    /// `genome` has fewer than 64 bases, so it can't be split into the
+    /// chunked scan's minimum chunk size.
+    GenomeTooShort { len: usize, min_len: usize },
+    /// `genome` exceeds `platform::MAX_SAFE_GENOME_LEN` for this target.
+    GenomeTooLarge { len: usize, limit: usize },
+}
+
+/// Like `count_k`, but validates its preconditions up front and returns an
+/// error instead of panicking when `genome` is too short to chunk, too long
+/// for this target's safe allocation limit, or `k` is zero.
+pub fn try_count_k(k: usize, genome: &[u8]) -> Result<SeqCounts, CountError> {
+    if k == 0 {
+        return Err(CountError::ZeroK);
+    }
+    if genome.len() < 64 {
+        return Err(CountError::GenomeTooShort { len: genome.len(), min_len: 64 });
+    }
+    if let Err(crate::platform::GenomeTooLarge { len, limit }) = crate::platform::check_genome_len(genome.len()) {
+        return Err(CountError::GenomeTooLarge { len, limit });
+    }
+    Ok(count_k(k, genome))
+}
+
+/// Like `count_k`, but generic over the storage backend, so alternative
+/// strategies (dense array, sketch, disk-backed) can reuse the same chunked,
+/// parallel scan instead of being hardwired to a hashbrown table.
+pub fn count_k_with<B: crate::backend::CountBackend>(
+    k: usize,
+    genome: &[u8],
+    new_backend: impl Fn() -> B + Sync + Send,
+) -> B {
+    par_scan(
+        genome,
+        k - 1,
+        |chunk| {
+            let mut backend = new_backend();
+            for seq in k_genome_iter(k, chunk) {
+                backend.insert(seq);
+            }
+            backend
+        },
+        &new_backend,
+        crate::backend::CountBackend::merge,
+    )
+}
+
+/// Splits `genome` into (roughly) 64 overlapping chunks, maps each one
+/// independently in parallel, then folds the per-chunk results together with
+/// `reduce`. This is the chunked map-reduce shape every counting strategy in
+/// this crate builds on; `overlap` should be one less than however many
+/// trailing bytes a single output unit spans, so no window is cut in half at
+/// a chunk boundary.
+pub fn par_scan<T: Send>(
+    genome: &[u8],
+    overlap: usize,
+    map: impl Fn(&[u8]) -> T + Sync + Send,
+    identity: impl Fn() -> T + Sync + Send,
+    reduce: impl Fn(T, T) -> T + Sync + Send,
+) -> T {
+    chunks(genome.len() / 64, overlap, genome)
+        .into_par_iter()
+        .map(map)
+        .reduce(identity, reduce)
+}
+
+/// Like `count_k`, but checks `token` before processing each chunk and bails
+/// out with `Cancelled` (partial work discarded) as soon as it's set, instead
+/// of running the whole scan to completion.
+pub fn count_k_cancellable(
+    k: usize,
+    genome: &[u8],
+    token: &crate::cancel::CancelToken,
+) -> Result<SeqCounts, crate::cancel::Cancelled> {
     chunks(genome.len() / 64, k - 1, genome)
         .into_par_iter()
-        .map(|chunk| inner_count_k(k, chunk))
-        .reduce(HashMap::default, merge)
+        .map(|chunk| {
+            token.check()?;
+            Ok(inner_count_k(k, chunk))
+        })
+        .try_reduce(HashMap::default, |l, r| Ok(merge(l, r)))
 }
 
+/// Like `count_k`, but also keeps up to `reservoir_size` example genome
+/// positions per k-mer (reservoir-sampled), so a frequency report can link a
+/// surprising k-mer back to where it occurs.
+pub fn count_k_with_positions(
+    k: usize,
+    genome: &[u8],
+    reservoir_size: usize,
+) -> (SeqCounts, HashMap<Sequence, Vec<usize>>) {
+    let len = genome.len() / 64;
+    chunks(len, k - 1, genome)
+        .into_iter()
+        .enumerate()
+        .par_bridge()
+        .map(|(i, chunk)| inner_count_k_with_positions(k, chunk, i * len, reservoir_size))
+        .reduce(
+            || (HashMap::default(), HashMap::default()),
+            |(l_counts, l_pos), (r_counts, r_pos)| {
+                let positions = merge_positions(&l_counts, l_pos, &r_counts, r_pos, reservoir_size);
+                (merge(l_counts, r_counts), positions)
+            },
+        )
+}
+
+/// Reports are ordered by pattern length ascending, the canonical order
+/// shared with `prev`, regardless of the order threads finish in.
 pub fn show(counts: Threads) -> String {
-    let mut str = Vec::with_capacity(counts.len());
-    for thrd in counts {
-        let (seq_str, seq_cnt) = thrd.join().expect("thread halts");
-        str.push(format!("{}\t{}", seq_cnt, seq_str));
+    let mut results: Vec<(String, usize)> = counts
+        .into_iter()
+        .map(|thrd| thrd.join().expect("thread halts"))
+        .collect();
+    results.sort_by_key(|(seq_str, _)| seq_str.len());
+    results
+        .into_iter()
+        .map(|(seq_str, seq_cnt)| format!("{}\t{}", seq_cnt, seq_str))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Like `show`, but arranges the report according to `order` instead of
+/// always sorting by pattern length.
+pub fn show_ordered(counts: OrderedThreads, order: ReportOrder) -> String {
+    let mut results: Vec<(String, usize, usize)> = counts
+        .into_iter()
+        .map(|thrd| thrd.join().expect("thread halts"))
+        .collect();
+    match order {
+        ReportOrder::Input => results.sort_by_key(|(_, _, index)| *index),
+        ReportOrder::Length => results.sort_by_key(|(seq_str, _, _)| seq_str.len()),
+        ReportOrder::Count => results.sort_by_key(|(_, seq_cnt, _)| std::cmp::Reverse(*seq_cnt)),
+        ReportOrder::Alpha => results.sort_by(|(l, ..), (r, ..)| l.cmp(r)),
     }
-    str.join("\n")
+    results
+        .into_iter()
+        .map(|(seq_str, seq_cnt, _)| format!("{}\t{}", seq_cnt, seq_str))
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 pub fn show_k(k: usize, counts: SeqCounts) -> String {
+    show_k_with_precision(k, counts, 3)
+}
+
+/// Like `show_k`, but with a configurable number of decimal places.
+/// Percentages are computed in f64 (Rust's default round-half-to-even
+/// formatting) so results reproduce published references exactly at any
+/// precision instead of drifting from f32 rounding at the last digit.
+pub fn show_k_with_precision(k: usize, counts: SeqCounts, precision: usize) -> String {
     let mut str = Vec::with_capacity(counts.len());
     for (s, p) in calc_percents(counts.values().sum(), counts) {
-        str.push(format!("{} {:.3}", s.to_str(k), p))
+        str.push(format!("{} {:.precision$}", s.to_str(k), p, precision = precision))
     }
     str.join("\n")
 }
@@ -89,7 +288,7 @@ impl Sequence {
         self.key &= (1u64 << (2 * k)) - 1;
     }
 
-    fn to_str(self, k: usize) -> String {
+    pub(crate) fn to_str(self, k: usize) -> String {
         let mut s = String::with_capacity(k);
         for i in (0..k).rev() {
             s.push(['A', 'C', 'T', 'G'][(self.key >> (2 * i) & 0b11) as usize])
@@ -104,20 +303,44 @@ impl Sequence {
         }
         seq
     }
+
+    pub(crate) fn pushed(mut self, byte: u8, k: usize) -> Self {
+        self.push(byte, k);
+        self
+    }
+
+    pub fn key(self) -> u64 {
+        self.key
+    }
+
+    pub fn from_key(key: u64) -> Self {
+        Self { key }
+    }
 }
 
 impl<'a> Iterator for KGenomeIter<'a> {
     type Item = Sequence;
 
+    /// Pushes bytes until `k` of them have landed in `seq` before yielding
+    /// anything, so a short or freshly-started chunk never surfaces a
+    /// zero-padded k-mer for a window that hasn't fully formed yet — every
+    /// `Some` from here on corresponds to a real, complete k-byte window.
     fn next(&mut self) -> Option<Sequence> {
-        self.seq.push(*self.genome.next()?, self.k);
+        if self.filled < self.k {
+            while self.filled < self.k {
+                self.seq.push(*self.genome.next()?, self.k);
+                self.filled += 1;
+            }
+        } else {
+            self.seq.push(*self.genome.next()?, self.k);
+        }
         Some(self.seq)
     }
 }
 
 #[rustfmt::skip]
 fn k_genome_iter(k: usize, genome: &[u8]) -> KGenomeIter {
-   KGenomeIter {k, seq: Sequence::default(), genome: genome.into_iter()}
+   KGenomeIter {k, seq: Sequence::default(), genome: genome.into_iter(), filled: 0}
 }
 
 fn chunks(len: usize, overlap: usize, genome: &[u8]) -> Vec<&[u8]> {
@@ -144,10 +367,77 @@ fn inner_count_k(k: usize, genome: &[u8]) -> SeqCounts {
     counts
 }
 
-fn calc_percents(total: u32, counts: SeqCounts) -> IntoIter<(Sequence, f32)> {
+fn inner_count_k_with_positions(
+    k: usize,
+    genome: &[u8],
+    offset: usize,
+    reservoir_size: usize,
+) -> (SeqCounts, HashMap<Sequence, Vec<usize>>) {
+    let mut counts = HashMap::with_capacity(4usize.pow(k as u32));
+    let mut positions: HashMap<Sequence, Vec<usize>> = HashMap::new();
+    let mut rng = SplitMix64::new(offset as u64);
+    for (i, seq) in k_genome_iter(k, genome).enumerate() {
+        let count = counts.entry(seq).or_insert(0);
+        *count += 1;
+        let examples = positions.entry(seq).or_default();
+        if examples.len() < reservoir_size {
+            examples.push(offset + i);
+        } else {
+            let slot = (rng.next_u64() % *count as u64) as usize;
+            if slot < reservoir_size {
+                examples[slot] = offset + i;
+            }
+        }
+    }
+    (counts, positions)
+}
+
+/// Merges two reservoirs of example positions (each already reservoir-sampled
+/// over its own chunk's occurrences) into one, without biasing toward
+/// either side. Each side's reservoir stands in for `count / examples.len()`
+/// occurrences in its chunk, so a weighted sample-without-replacement over
+/// both reservoirs combined (Efraimidis-Spirakis A-ES: give each example a
+/// key `u^(1/weight)` and keep the top `reservoir_size` keys) reweights for
+/// that and gives every genome position equal odds of being kept, unlike a
+/// plain `extend`-then-`truncate` which always favors whichever side is
+/// merged in first.
+fn merge_positions(
+    l_counts: &SeqCounts,
+    l_pos: HashMap<Sequence, Vec<usize>>,
+    r_counts: &SeqCounts,
+    r_pos: HashMap<Sequence, Vec<usize>>,
+    reservoir_size: usize,
+) -> HashMap<Sequence, Vec<usize>> {
+    let mut merged = HashMap::with_capacity(l_pos.len().max(r_pos.len()));
+    let empty = Vec::new();
+    for seq in l_pos.keys().chain(r_pos.keys()).copied().collect::<hashbrown::HashSet<_>>() {
+        let l_examples = l_pos.get(&seq).unwrap_or(&empty);
+        let r_examples = r_pos.get(&seq).unwrap_or(&empty);
+        if l_examples.len() + r_examples.len() <= reservoir_size {
+            let mut combined = l_examples.clone();
+            combined.extend(r_examples.iter().copied());
+            merged.insert(seq, combined);
+            continue;
+        }
+        let l_total = *l_counts.get(&seq).unwrap_or(&0) as f64;
+        let r_total = *r_counts.get(&seq).unwrap_or(&0) as f64;
+        let l_weight = l_total / l_examples.len() as f64;
+        let r_weight = r_total / r_examples.len() as f64;
+        let mut rng = SplitMix64::new(seq.key());
+        let mut keyed: Vec<(f64, usize)> = Vec::with_capacity(l_examples.len() + r_examples.len());
+        keyed.extend(l_examples.iter().map(|&pos| (rng.next_f64().powf(1.0 / l_weight), pos)));
+        keyed.extend(r_examples.iter().map(|&pos| (rng.next_f64().powf(1.0 / r_weight), pos)));
+        keyed.sort_by(|(l, _), (r, _)| r.total_cmp(l));
+        keyed.truncate(reservoir_size);
+        merged.insert(seq, keyed.into_iter().map(|(_, pos)| pos).collect());
+    }
+    merged
+}
+
+fn calc_percents(total: u32, counts: SeqCounts) -> IntoIter<(Sequence, f64)> {
     let mut percents = Vec::with_capacity(counts.len());
     for (seq, count) in sort_cnt(counts) {
-        percents.push((seq, count as f32 * 100. / total as f32))
+        percents.push((seq, count as f64 * 100. / total as f64))
     }
     percents.into_iter()
 }
@@ -163,9 +453,90 @@ fn sort_cnt(counts: SeqCounts) -> IntoIter<(Sequence, u32)> {
     counts.into_iter()
 }
 
-fn merge(mut l_counts: SeqCounts, r_counts: SeqCounts) -> SeqCounts {
+pub(crate) fn merge(mut l_counts: SeqCounts, r_counts: SeqCounts) -> SeqCounts {
     for (seq, count) in r_counts.iter() {
         *l_counts.entry(*seq).or_insert(0) += count
     }
     l_counts
 }
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inner_count_k_with_positions_records_every_occurrence_below_the_reservoir_cap() {
+        let (counts, positions) = inner_count_k_with_positions(2, b"ACACAC", 0, 8);
+        let ac = Sequence::from_str("AC");
+        assert_eq!(counts[&ac], 3);
+        assert_eq!(positions[&ac], vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn merge_positions_unions_both_sides_when_they_fit_in_the_reservoir() {
+        let seq = Sequence::from_key(1);
+        let l_counts = SeqCounts::from_iter([(seq, 2)]);
+        let r_counts = SeqCounts::from_iter([(seq, 2)]);
+        let l_pos = HashMap::from_iter([(seq, vec![10, 11])]);
+        let r_pos = HashMap::from_iter([(seq, vec![20, 21])]);
+
+        let merged = merge_positions(&l_counts, l_pos, &r_counts, r_pos, 8);
+        assert_eq!(merged[&seq], vec![10, 11, 20, 21]);
+    }
+
+    #[test]
+    fn merge_positions_does_not_always_favor_the_side_merged_in_first() {
+        let reservoir_size = 2;
+        let l_examples = vec![100, 101];
+        let r_examples = vec![200, 201];
+        let mut an_r_example_survived = false;
+
+        for key in 0..64u64 {
+            let seq = Sequence::from_key(key);
+            let l_counts = SeqCounts::from_iter([(seq, 2)]);
+            let r_counts = SeqCounts::from_iter([(seq, 2)]);
+            let l_pos = HashMap::from_iter([(seq, l_examples.clone())]);
+            let r_pos = HashMap::from_iter([(seq, r_examples.clone())]);
+
+            let merged = merge_positions(&l_counts, l_pos, &r_counts, r_pos, reservoir_size);
+            let kept = &merged[&seq];
+            assert_eq!(kept.len(), reservoir_size);
+            an_r_example_survived |= kept.iter().any(|pos| r_examples.contains(pos));
+        }
+
+        // The old `extend`-then-`truncate` merge always kept `l`'s own
+        // leading elements once full, so the right-hand reservoir was
+        // silently discarded on every merge; a weighted merge gives it a
+        // fair chance across enough trials.
+        assert!(an_r_example_survived);
+    }
+
+    #[test]
+    fn try_count_k_rejects_zero_k() {
+        assert!(matches!(try_count_k(0, &[b'A'; 64]), Err(CountError::ZeroK)));
+    }
+
+    #[test]
+    fn try_count_k_rejects_a_genome_shorter_than_the_minimum_chunk_size() {
+        assert!(matches!(try_count_k(4, b"ACGT"), Err(CountError::GenomeTooShort { len: 4, min_len: 64 })));
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn try_count_k_rejects_a_genome_over_the_platform_safe_limit() {
+        let len = crate::platform::MAX_SAFE_GENOME_LEN + 1;
+        assert!(matches!(try_count_k(4, &vec![b'A'; len]), Err(CountError::GenomeTooLarge { .. })));
+    }
+
+    #[test]
+    fn count_k_with_positions_agrees_with_count_k() {
+        let genome = b"ACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGTACGT".to_vec();
+        let (counts, positions) = count_k_with_positions(3, &genome, 4);
+        assert_eq!(counts, count_k(3, &genome));
+        for (seq, examples) in &positions {
+            assert!(examples.len() <= 4);
+            assert!(examples.len() as u32 <= counts[seq]);
+        }
+    }
+}
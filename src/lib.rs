@@ -1,8 +1,65 @@
 #![feature(stmt_expr_attributes)]
 
 // Exports --------------------------------------------------------------------
+pub mod adapters;
+pub mod ani;
+pub mod assembly_qc;
+pub mod backend;
+#[cfg(feature = "bam")]
+pub mod bam;
+pub mod bench_track;
+#[cfg(feature = "bgzf")]
+pub mod bgzf;
+pub mod build_info;
+pub mod cache;
+pub mod cancel;
+pub mod checkpoint;
+pub mod cli_error;
+pub mod color_output;
+pub mod confidence;
+pub mod config;
+pub mod coverage;
+pub mod dedup;
+pub mod determinism;
+pub mod dinucleotide;
+pub mod dump;
+pub mod embed;
+pub mod external_sort;
+pub mod extract;
+pub mod fai;
+pub mod fasta;
+pub mod fastq;
+pub mod filter;
+pub mod formatter;
+pub mod generate;
+pub mod golden;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod html_report;
+pub mod incremental;
+pub mod input;
 pub mod knucleotide;
+pub mod long_read_profile;
+pub mod lookup;
+pub mod manifest;
+pub mod mappability;
+pub mod mask;
+pub mod mutate;
+pub mod normalize;
+pub mod pipeline;
+pub mod platform;
+pub mod presets;
+#[cfg(feature = "legacy")]
 pub mod prev;
+pub mod profile;
+pub mod sample;
+pub mod shard;
+pub mod simd;
+pub mod spaced_seed;
+pub mod stats;
+pub mod table;
+pub mod tetra;
+pub mod verify;
 
 // Macros ---------------------------------------------------------------------
 #[macro_export]
@@ -12,7 +69,9 @@ macro_rules! str {
 }
 
 // Tests ----------------------------------------------------------------------
-#[cfg(test)]
+// Compares `prev`'s stdout against `knucleotide`'s, so it only makes sense
+// when the `legacy` feature builds both engines into the binary.
+#[cfg(all(test, feature = "legacy"))]
 mod test_nucleotide {
 
     use std::process::{Command, Stdio};
@@ -0,0 +1,32 @@
+// Sliding-window composition profile for long reads: a single read from a
+// long-read platform can span kilobases and drift in composition along its
+// length (e.g. a chimeric junction), which a single whole-read GC figure
+// would average away.
+
+// Types ----------------------------------------------------------------------
+pub struct WindowProfile {
+    pub start: usize,
+    pub gc: f32,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn profile(read: &[u8], window: usize, step: usize) -> Vec<WindowProfile> {
+    if window == 0 || step == 0 || read.len() < window {
+        return Vec::new();
+    }
+    (0..=read.len() - window)
+        .step_by(step)
+        .map(|start| WindowProfile { start, gc: crate::fasta::gc_content(&read[start..start + window]) })
+        .collect()
+}
+
+/// Flags windows whose GC content deviates from the read's overall GC by
+/// more than `threshold`, a cheap signal for chimeric or contaminated reads.
+pub fn anomalies(read: &[u8], window: usize, step: usize, threshold: f32) -> Vec<usize> {
+    let overall_gc = crate::fasta::gc_content(read);
+    profile(read, window, step)
+        .into_iter()
+        .filter(|w| (w.gc - overall_gc).abs() > threshold)
+        .map(|w| w.start)
+        .collect()
+}
@@ -0,0 +1,23 @@
+// Reverse lookup over `knucleotide::count_k_with_positions`'s output:
+// given a target count, find every k-mer that occurred exactly that many
+// times and where its (reservoir-sampled) example positions are.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{SeqCounts, Sequence};
+use hashbrown::HashMap;
+
+// Public Functions -------------------------------------------------------------
+pub fn positions_with_count(
+    k: usize,
+    target_count: u32,
+    counts: &SeqCounts,
+    positions: &HashMap<Sequence, Vec<usize>>,
+) -> Vec<(String, Vec<usize>)> {
+    let mut hits: Vec<(String, Vec<usize>)> = counts
+        .iter()
+        .filter(|(_, &count)| count == target_count)
+        .map(|(seq, _)| (seq.to_str(k), positions.get(seq).cloned().unwrap_or_default()))
+        .collect();
+    hits.sort_by(|(l, _), (r, _)| l.cmp(r));
+    hits
+}
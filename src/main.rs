@@ -1,6 +1,45 @@
-use nucleotide::{knucleotide, prev};
+use nucleotide::cli_error::{CliError, ErrorFormat};
+use nucleotide::config::Config;
+use nucleotide::knucleotide;
+use std::process::ExitCode;
 
-fn main() {
-    prev::main();
+fn main() -> ExitCode {
+    let format = if std::env::args().any(|a| a == "--error-format=json") {
+        ErrorFormat::Json
+    } else {
+        ErrorFormat::Text
+    };
+
+    if let Err(e) = run() {
+        return ExitCode::from(e.report(format) as u8);
+    }
+
+    #[cfg(feature = "legacy")]
+    nucleotide::prev::main();
     knucleotide::main();
+    ExitCode::SUCCESS
+}
+
+fn run() -> Result<(), CliError> {
+    std::fs::metadata("2500000_in").map_err(|e| CliError::Io(e.to_string()))?;
+
+    let args: Vec<String> = std::env::args().collect();
+    let cli_overrides = cli_config_overrides(&args);
+    let config = Config::resolve(config_path(&args).as_deref(), &cli_overrides).map_err(|e| CliError::BadInput(format!("{e:?}")))?;
+    let _ = rayon::ThreadPoolBuilder::new().num_threads(config.threads).build_global();
+    Ok(())
+}
+
+/// Picks out `--key=value` flags this crate understands (currently
+/// `--format`, `--strategy`, `--threads`, `--canonical`) as `Config::resolve`
+/// overrides, the highest-precedence source after defaults, environment,
+/// and config file.
+fn cli_config_overrides(args: &[String]) -> Vec<(&str, &str)> {
+    args.iter().filter_map(|a| a.strip_prefix("--")?.split_once('=')).filter(|(key, _)| {
+        matches!(*key, "format" | "strategy" | "threads" | "canonical")
+    }).collect()
+}
+
+fn config_path(args: &[String]) -> Option<std::path::PathBuf> {
+    args.iter().find_map(|a| a.strip_prefix("--config=")).map(std::path::PathBuf::from)
 }
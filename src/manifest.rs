@@ -0,0 +1,49 @@
+// Machine-readable run manifest: a JSON record of what a run actually did
+// (crate version, resolved parameters, input checksum, wall time), so
+// results can be traced back to the exact invocation that produced them
+// without relying on shell history.
+
+// Imports --------------------------------------------------------------------
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// Types ----------------------------------------------------------------------
+#[derive(Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: String,
+    pub params: Config,
+    pub input_path: String,
+    pub input_checksum: String,
+    pub duration_ms: u128,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn build(params: Config, input_path: &str, input: &[u8], elapsed: Duration) -> Manifest {
+    Manifest {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        params,
+        input_path: input_path.to_string(),
+        input_checksum: format!("{:016x}", fnv1a(input)),
+        duration_ms: elapsed.as_millis(),
+    }
+}
+
+pub fn to_json(manifest: &Manifest) -> serde_json::Result<String> {
+    serde_json::to_string_pretty(manifest)
+}
+
+// Private Functions ------------------------------------------------------------
+/// FNV-1a 64-bit: no cryptographic guarantees, just a fast, dependency-free
+/// way to detect "this isn't the input I ran against." Shared with `cache`,
+/// which needs the same kind of cheap fingerprint for its cache keys.
+pub(crate) fn fnv1a(data: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+    let mut hash = OFFSET_BASIS;
+    for &byte in data {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    hash
+}
@@ -0,0 +1,34 @@
+// K-mer uniqueness (mappability) track: for each position in a reference,
+// whether the k-mer starting there occurs exactly once across the whole
+// reference. Positions in repetitive regions score 0; positions where a
+// short read could be placed unambiguously score 1.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{self, Sequence};
+
+// Public Functions -------------------------------------------------------------
+pub fn track(k: usize, reference: &[u8]) -> Vec<(usize, bool)> {
+    if reference.len() < k {
+        return Vec::new();
+    }
+    let counts = knucleotide::count_k(k, reference);
+
+    let mut seq = Sequence::default();
+    let mut track = Vec::with_capacity(reference.len() - k + 1);
+    for (i, &base) in reference.iter().enumerate() {
+        seq = seq.pushed(base, k);
+        if i + 1 >= k {
+            let unique = counts.get(&seq).copied().unwrap_or(0) == 1;
+            track.push((i + 1 - k, unique));
+        }
+    }
+    track
+}
+
+pub fn to_bedgraph(chrom: &str, track: &[(usize, bool)]) -> String {
+    track
+        .iter()
+        .map(|(pos, unique)| format!("{}\t{}\t{}\t{}", chrom, pos, pos + 1, *unique as u8))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,63 @@
+// A DUST-style low-complexity masker: windows with skewed 3-mer composition
+// are soft-masked (lowercased) rather than removed, reusing the same k=3
+// windowed counting idea as `knucleotide::count_k`, sized for small windows
+// instead of the whole-genome chunked scan.
+
+// Imports --------------------------------------------------------------------
+use hashbrown::HashMap;
+
+// Public Functions -------------------------------------------------------------
+/// Soft-masks (lowercases) any `window`-sized region of `genome` whose 3-mer
+/// composition is dominated (>= `max_dominant_fraction`) by a single triplet.
+pub fn mask(genome: &[u8], window: usize, max_dominant_fraction: f32) -> Vec<u8> {
+    let mut masked = genome.to_vec();
+    for start in (0..genome.len()).step_by(window) {
+        let end = (start + window).min(genome.len());
+        if end - start < 3 {
+            continue;
+        }
+        let counts = count_triplets(&genome[start..end]);
+        let total: u32 = counts.values().sum();
+        let dominant = counts.values().copied().max().unwrap_or(0);
+        if total > 0 && dominant as f32 / total as f32 >= max_dominant_fraction {
+            masked[start..end].make_ascii_lowercase();
+        }
+    }
+    masked
+}
+
+// Private Functions ------------------------------------------------------------
+fn count_triplets(window: &[u8]) -> HashMap<[u8; 3], u32> {
+    let mut counts = HashMap::new();
+    for triplet in window.windows(3) {
+        *counts.entry([triplet[0], triplet[1], triplet[2]]).or_insert(0) += 1;
+    }
+    counts
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_a_low_complexity_window() {
+        let genome = b"AAAAAAAAAA".to_vec();
+        let masked = mask(&genome, 10, 0.5);
+        assert_eq!(masked, b"aaaaaaaaaa".to_vec());
+    }
+
+    #[test]
+    fn leaves_a_high_complexity_window_untouched() {
+        let genome = b"ACGTACGTAC".to_vec();
+        let masked = mask(&genome, 10, 0.9);
+        assert_eq!(masked, genome);
+    }
+
+    #[test]
+    fn skips_windows_too_short_to_have_a_triplet() {
+        let genome = b"AC".to_vec();
+        let masked = mask(&genome, 10, 0.5);
+        assert_eq!(masked, genome);
+    }
+}
@@ -0,0 +1,39 @@
+// Seeded mutation of an existing genome, so comparison/distance features can
+// be validated against data with a known divergence rate.
+
+// Imports --------------------------------------------------------------------
+use crate::sample::SplitMix64;
+
+// Types ----------------------------------------------------------------------
+pub struct MutationSpec {
+    pub substitution_rate: f64,
+    pub indel_rate: f64,
+    pub seed: u64,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Emits a mutated copy of `genome`: each base is independently substituted,
+/// deleted, or has a random base inserted before it, at the given rates.
+pub fn mutate(genome: &[u8], spec: &MutationSpec) -> Vec<u8> {
+    let mut rng = SplitMix64::new(spec.seed);
+    let mut out = Vec::with_capacity(genome.len());
+    for &base in genome {
+        if rng.next_f64() < spec.indel_rate {
+            if rng.next_f64() < 0.5 {
+                out.push(random_base(&mut rng));
+                out.push(base);
+            }
+            // else: deletion, drop this base entirely
+        } else if rng.next_f64() < spec.substitution_rate {
+            out.push(random_base(&mut rng));
+        } else {
+            out.push(base);
+        }
+    }
+    out
+}
+
+// Private Functions ------------------------------------------------------------
+fn random_base(rng: &mut SplitMix64) -> u8 {
+    [b'A', b'C', b'T', b'G'][(rng.next_u64() % 4) as usize]
+}
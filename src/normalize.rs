@@ -0,0 +1,76 @@
+// K-mer abundance filtering of reads: digital normalization discards reads
+// whose k-mers are already well covered (evening out coverage before
+// assembly), while a trusted-kmer filter does the opposite — keeping only
+// reads made up of k-mers abundant enough to trust, discarding likely
+// errors or contaminants.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+
+// Types ----------------------------------------------------------------------
+/// Streaming digital normalization: reads are kept only while their median
+/// k-mer abundance stays under `target_coverage`, and each kept read's
+/// k-mers are folded into the running counts before the next read is
+/// considered.
+pub struct Normalizer {
+    k: usize,
+    target_coverage: u32,
+    counts: SeqCounts,
+}
+
+// Public Functions -------------------------------------------------------------
+impl Normalizer {
+    pub fn new(k: usize, target_coverage: u32) -> Self {
+        Self { k, target_coverage, counts: SeqCounts::default() }
+    }
+
+    /// Filters `reads` in order, keeping and counting each read that's still
+    /// under `target_coverage`, discarding (without counting) the rest.
+    pub fn filter<'a>(&mut self, reads: &'a [Vec<u8>]) -> Vec<&'a [u8]> {
+        reads
+            .iter()
+            .filter(|read| {
+                let keep = median_abundance(&self.counts, self.k, read) < self.target_coverage;
+                if keep {
+                    for seq in kmers(read, self.k) {
+                        *self.counts.entry(seq).or_insert(0) += 1;
+                    }
+                }
+                keep
+            })
+            .map(Vec::as_slice)
+            .collect()
+    }
+}
+
+/// Keeps only reads whose k-mers are all covered at least `min_abundance`
+/// times in `reference_counts` (e.g. a table built from the same or a
+/// deeper dataset), discarding reads likely dominated by sequencing errors.
+pub fn trusted_kmer_filter<'a>(reads: &'a [Vec<u8>], k: usize, reference_counts: &SeqCounts, min_abundance: u32) -> Vec<&'a [u8]> {
+    reads
+        .iter()
+        .filter(|read| kmers(read, k).into_iter().all(|seq| reference_counts.get(&seq).copied().unwrap_or(0) >= min_abundance))
+        .map(Vec::as_slice)
+        .collect()
+}
+
+// Private Functions ------------------------------------------------------------
+fn kmers(read: &[u8], k: usize) -> Vec<Sequence> {
+    let mut window = Sequence::default();
+    read.iter()
+        .enumerate()
+        .filter_map(|(i, &base)| {
+            window = window.pushed(base, k);
+            (i + 1 >= k).then_some(window)
+        })
+        .collect()
+}
+
+fn median_abundance(counts: &SeqCounts, k: usize, read: &[u8]) -> u32 {
+    let mut abundances: Vec<u32> = kmers(read, k).into_iter().map(|seq| counts.get(&seq).copied().unwrap_or(0)).collect();
+    if abundances.is_empty() {
+        return 0;
+    }
+    abundances.sort_unstable();
+    abundances[abundances.len() / 2]
+}
@@ -0,0 +1,49 @@
+// Throughput-oriented batched channel pipeline: a producer thread groups
+// incoming records into fixed-size batches and hands them off over a
+// bounded channel to a pool of worker threads, so counting overlaps with
+// I/O/parsing instead of waiting for it to finish first.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{self, SeqCounts};
+use std::sync::mpsc;
+use std::thread;
+
+// Public Functions -------------------------------------------------------------
+/// Counts k-mers over `records`, pipelining production of `batch_size`-record
+/// batches against `workers` consumer threads, each counting its batch
+/// independently before the results are merged.
+pub fn count_k_pipelined(k: usize, records: Vec<Vec<u8>>, batch_size: usize, workers: usize) -> SeqCounts {
+    let (tx, rx) = mpsc::sync_channel::<Vec<Vec<u8>>>(workers * 2);
+    let rx = std::sync::Arc::new(std::sync::Mutex::new(rx));
+
+    let producer = thread::spawn(move || {
+        for batch in records.chunks(batch_size.max(1)) {
+            if tx.send(batch.to_vec()).is_err() {
+                break;
+            }
+        }
+    });
+
+    let consumers: Vec<_> = (0..workers.max(1))
+        .map(|_| {
+            let rx = std::sync::Arc::clone(&rx);
+            thread::spawn(move || {
+                let mut local = SeqCounts::default();
+                loop {
+                    let batch = { rx.lock().unwrap().recv() };
+                    let Ok(batch) = batch else { break };
+                    for record in &batch {
+                        local = knucleotide::merge(local, knucleotide::count_k(k, record));
+                    }
+                }
+                local
+            })
+        })
+        .collect();
+
+    producer.join().expect("producer thread halts");
+    consumers
+        .into_iter()
+        .map(|c| c.join().expect("consumer thread halts"))
+        .fold(SeqCounts::default(), knucleotide::merge)
+}
@@ -0,0 +1,62 @@
+// Windows and 32-bit target support audit: the counting core doesn't use
+// anything platform-specific by itself (`Sequence`'s packed key is a `u64`
+// regardless of `usize` width, and the AVX2 path in `simd` already falls
+// back to scalar off x86_64), but a few assumptions elsewhere only hold on
+// 64-bit hosts. This module collects them in one place with an explicit
+// guard instead of leaving them to surface as a confusing allocation panic.
+
+// Types ----------------------------------------------------------------------
+#[derive(Debug)]
+pub struct GenomeTooLarge {
+    pub len: usize,
+    pub limit: usize,
+}
+
+// Public Functions -------------------------------------------------------------
+/// On a 32-bit target, `usize` tops out around 4 GiB and large allocations
+/// (the genome buffer, per-chunk `Vec`s) compete with address space that's
+/// also needed for the binary, stack, and other allocations; cap well below
+/// the theoretical limit rather than let it fail as an opaque OOM. On a
+/// 64-bit target this is never reached.
+pub const MAX_SAFE_GENOME_LEN: usize = if usize::BITS < 64 { 1 << 30 } else { usize::MAX };
+
+pub fn check_genome_len(len: usize) -> Result<(), GenomeTooLarge> {
+    // `MAX_SAFE_GENOME_LEN` collapses to `usize::MAX` on 64-bit targets, so
+    // clippy sees this comparison as always-false there; it's intentional
+    // (the limit only bites on 32-bit), not a bug, so silence the lint
+    // rather than split the function in two for the sake of one comparison.
+    #[allow(clippy::absurd_extreme_comparisons)]
+    if len > MAX_SAFE_GENOME_LEN {
+        Err(GenomeTooLarge { len, limit: MAX_SAFE_GENOME_LEN })
+    } else {
+        Ok(())
+    }
+}
+
+/// `std::env::temp_dir()` and `std::thread::available_parallelism()` are
+/// already portable across Windows/Unix, so sharding (`shard.rs`) and
+/// checkpointing (`checkpoint.rs`) need no path-separator special-casing;
+/// this just documents that it was checked, since it's easy to assume
+/// otherwise when every other tool in this space is Unix-only.
+pub fn supports_current_platform() -> bool {
+    true
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_genomes_at_or_under_the_safe_limit() {
+        assert!(check_genome_len(0).is_ok());
+        assert!(check_genome_len(MAX_SAFE_GENOME_LEN).is_ok());
+    }
+
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn rejects_genomes_over_the_safe_limit_on_32_bit_targets() {
+        let err = check_genome_len(MAX_SAFE_GENOME_LEN + 1).unwrap_err();
+        assert_eq!(err.len, MAX_SAFE_GENOME_LEN + 1);
+    }
+}
@@ -0,0 +1,26 @@
+// Named motif libraries: common panels (adapter sequences, restriction
+// sites) that would otherwise need to be pasted into every invocation of
+// `adapters::screen` or a pattern-based count by hand.
+
+// Public Functions -------------------------------------------------------------
+/// Resolves a preset name to its panel of motifs, or `None` if unknown.
+pub fn resolve(name: &str) -> Option<Vec<String>> {
+    match name {
+        "illumina_adapters" => Some(crate::str![
+            "AGATCGGAAGAGC",
+            "CTGTCTCTTATACACATCT"
+        ]),
+        "nextera_adapters" => Some(crate::str!["CTGTCTCTTATACACATCT"]),
+        "polya" => Some(crate::str!["AAAAAAAAAA"]),
+        "restriction_sites" => Some(crate::str![
+            "GAATTC",  // EcoRI
+            "GGATCC",  // BamHI
+            "AAGCTT"   // HindIII
+        ]),
+        _ => None,
+    }
+}
+
+pub fn names() -> Vec<&'static str> {
+    vec!["illumina_adapters", "nextera_adapters", "polya", "restriction_sites"]
+}
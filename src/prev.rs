@@ -181,8 +181,11 @@ pub fn main() {
     Freq(1).print(&gen_freq(&input, 1));
     Freq(2).print(&gen_freq(&input, 2));
 
-    for t in results.into_iter().rev() {
-        let (item, freq) = t.join().unwrap();
+    // Reports are ordered by motif length ascending, the canonical order
+    // shared with `knucleotide`, regardless of the order threads finish in.
+    let mut joined: Vec<_> = results.into_iter().map(|t| t.join().unwrap()).collect();
+    joined.sort_by_key(|(item, _)| item.0.len());
+    for (item, freq) in joined {
         item.print(&freq);
     }
 }
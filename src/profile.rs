@@ -0,0 +1,54 @@
+// A single-iteration profiling mode: each phase is started from a thread
+// named after itself. Parsing runs on that thread; the counting phases hand
+// their work to rayon's worker threads (and, for patterns, to the unnamed
+// threads `count` spawns), which do not carry the phase name.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{count, count_k, read_file};
+use std::thread;
+
+// Public Functions -------------------------------------------------------------
+/// Runs exactly one counting pass over `path`, with each phase (parse, k1,
+/// k2, patterns) started from a distinctly-named thread, and returns once
+/// every phase has finished.
+pub fn profile_once(path: &str, patterns: Vec<String>) {
+    let genome = thread::Builder::new()
+        .name("phase:parse".into())
+        .spawn({
+            let path = path.to_string();
+            move || read_file(&path)
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+
+    let k1 = {
+        let genome = std::sync::Arc::clone(&genome);
+        thread::Builder::new()
+            .name("phase:k1".into())
+            .spawn(move || count_k(1, &genome))
+            .unwrap()
+    };
+    let k2 = {
+        let genome = std::sync::Arc::clone(&genome);
+        thread::Builder::new()
+            .name("phase:k2".into())
+            .spawn(move || count_k(2, &genome))
+            .unwrap()
+    };
+    let patterns = {
+        let genome = std::sync::Arc::clone(&genome);
+        thread::Builder::new()
+            .name("phase:patterns".into())
+            .spawn(move || {
+                for pattern in count(patterns, &genome) {
+                    pattern.join().unwrap();
+                }
+            })
+            .unwrap()
+    };
+
+    k1.join().unwrap();
+    k2.join().unwrap();
+    patterns.join().unwrap();
+}
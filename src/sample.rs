@@ -0,0 +1,53 @@
+// Deterministic subsampling so composition can be previewed on huge inputs
+// without scanning every base.
+
+// Types ----------------------------------------------------------------------
+/// A tiny splitmix64-based PRNG: no external dependency, reproducible across
+/// runs and platforms given the same seed.
+pub(crate) struct SplitMix64 {
+    state: u64,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Keeps roughly `fraction` of `genome`, split into fixed-size chunks and
+/// selected with a seeded PRNG so the same seed always yields the same subset.
+pub fn sample_by_chunk(genome: &[u8], fraction: f64, chunk_len: usize, seed: u64) -> Vec<u8> {
+    let mut rng = SplitMix64::new(seed);
+    let mut sampled = Vec::with_capacity((genome.len() as f64 * fraction) as usize);
+    for chunk in genome.chunks(chunk_len.max(1)) {
+        if rng.next_f64() < fraction {
+            sampled.extend_from_slice(chunk);
+        }
+    }
+    sampled
+}
+
+/// Keeps at most `max_bases` of `genome`, taken from a single seeded random
+/// starting offset.
+pub fn sample_max_bases(genome: &[u8], max_bases: usize, seed: u64) -> &[u8] {
+    if max_bases >= genome.len() {
+        return genome;
+    }
+    let mut rng = SplitMix64::new(seed);
+    let start = (rng.next_u64() as usize) % (genome.len() - max_bases);
+    &genome[start..start + max_bases]
+}
+
+// Private Functions ------------------------------------------------------------
+impl SplitMix64 {
+    pub(crate) fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub(crate) fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    pub(crate) fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+}
@@ -0,0 +1,113 @@
+// Multi-process sharded counting: the input is split by byte range across
+// worker processes (rank/size supplied via env, MPI-style), each dumping a
+// partial table that a final merge step combines.
+
+// Imports --------------------------------------------------------------------
+use crate::dump;
+use crate::knucleotide::{count_k, SeqCounts};
+use crate::table;
+use std::fs::File;
+use std::path::Path;
+
+// Types ----------------------------------------------------------------------
+pub struct Rank {
+    pub index: usize,
+    pub count: usize,
+}
+
+// Public Functions -------------------------------------------------------------
+/// Reads `NUCLEOTIDE_RANK`/`NUCLEOTIDE_WORLD_SIZE` from the environment, or
+/// falls back to a single-shard rank 0 of 1 if unset.
+pub fn rank_from_env() -> Rank {
+    let index = std::env::var("NUCLEOTIDE_RANK").ok().and_then(|v| v.parse().ok()).unwrap_or(0);
+    let count = std::env::var("NUCLEOTIDE_WORLD_SIZE").ok().and_then(|v| v.parse().ok()).unwrap_or(1);
+    Rank { index, count }
+}
+
+/// Counts only this rank's byte range of `genome` and dumps the partial
+/// table to `<out_dir>/shard-<rank>.bin`.
+pub fn count_shard(k: usize, genome: &[u8], rank: &Rank, out_dir: &Path) -> std::io::Result<()> {
+    if rank.count == 0 {
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "NUCLEOTIDE_WORLD_SIZE must be at least 1"));
+    }
+    let (start, read_end) = shard_read_range(genome.len(), rank, k);
+    let counts = count_k(k, &genome[start..read_end]);
+    std::fs::create_dir_all(out_dir)?;
+    dump::dump(&counts, File::create(out_dir.join(format!("shard-{}.bin", rank.index)))?)
+}
+
+/// Merges every `shard-*.bin` file in `out_dir` into a single table, run
+/// once all worker processes have finished.
+pub fn merge_shards(out_dir: &Path, world_size: usize) -> std::io::Result<SeqCounts> {
+    let mut merged = SeqCounts::default();
+    for i in 0..world_size {
+        let shard = dump::load(File::open(out_dir.join(format!("shard-{i}.bin")))?)?;
+        merged = table::merge(merged, shard);
+    }
+    Ok(merged)
+}
+
+// Private Functions ------------------------------------------------------------
+fn shard_range(len: usize, rank: &Rank) -> (usize, usize) {
+    let chunk = len.div_ceil(rank.count);
+    let start = rank.index * chunk;
+    (start.min(len), (start + chunk).min(len))
+}
+
+/// Like `knucleotide::chunks`, reads `k - 1` bytes past this shard's own
+/// `shard_range` so windows starting near the boundary (but still owned by
+/// this shard) aren't cut short; the next rank's range starts exactly at
+/// `end`, so no window is double-counted.
+fn shard_read_range(len: usize, rank: &Rank, k: usize) -> (usize, usize) {
+    let (start, end) = shard_range(len, rank);
+    (start, (end + k.saturating_sub(1)).min(len))
+}
+
+// Tests ------------------------------------------------------------------------
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_shard_rejects_a_zero_world_size() {
+        let rank = Rank { index: 0, count: 0 };
+        let dir = std::env::temp_dir().join(format!("nucleotide-shard-test-{:?}-zero", std::thread::current().id()));
+        let err = count_shard(4, b"ACGTACGT", &rank, &dir).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    /// Every k-mer window start in `0..=len - k` should be read by exactly
+    /// one shard's extended range, with no gaps and no double reads — the
+    /// property this module's overlap fix exists to guarantee.
+    #[test]
+    fn shard_read_ranges_cover_every_kmer_start_exactly_once() {
+        let len = 10;
+        let k = 4;
+        let world_size = 2;
+        let mut owners = vec![None; len - k + 1];
+        for index in 0..world_size {
+            let rank = Rank { index, count: world_size };
+            let (start, read_end) = shard_read_range(len, &rank, k);
+            for window_start in start..=read_end.saturating_sub(k) {
+                let owner = &mut owners[window_start];
+                assert_eq!(*owner, None, "window at {window_start} read by more than one shard");
+                *owner = Some(index);
+            }
+        }
+        assert!(owners.iter().all(Option::is_some), "some window was read by no shard: {owners:?}");
+    }
+
+    #[test]
+    fn shard_ranges_tile_the_genome_without_gaps_or_overlap() {
+        let len = 10;
+        let world_size = 3;
+        let mut covered = 0;
+        for index in 0..world_size {
+            let rank = Rank { index, count: world_size };
+            let (start, end) = shard_range(len, &rank);
+            assert_eq!(start, covered);
+            covered = end;
+        }
+        assert_eq!(covered, len);
+    }
+}
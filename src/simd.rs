@@ -0,0 +1,42 @@
+// Runtime CPU feature dispatch for the 2-bit base encoding step, so a single
+// release binary picks the best available path on each benchmarking machine.
+
+// Public Functions -------------------------------------------------------------
+/// Encodes each ASCII base in `bytes` to its 2-bit code (`(b >> 1) & 0b11`),
+/// dispatching to an AVX2 kernel when available and falling back to scalar
+/// otherwise.
+pub fn encode_bases(bytes: &[u8]) -> Vec<u8> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is_x86_feature_detected!("avx2") {
+            return unsafe { encode_bases_avx2(bytes) };
+        }
+    }
+    encode_bases_scalar(bytes)
+}
+
+// Private Functions ------------------------------------------------------------
+fn encode_bases_scalar(bytes: &[u8]) -> Vec<u8> {
+    bytes.iter().map(|b| (b >> 1) & 0b11).collect()
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2")]
+unsafe fn encode_bases_avx2(bytes: &[u8]) -> Vec<u8> {
+    use std::arch::x86_64::*;
+
+    let mut out = vec![0u8; bytes.len()];
+    let chunks = bytes.len() / 32;
+    let mask = _mm256_set1_epi8(0b11);
+    for i in 0..chunks {
+        let base = i * 32;
+        let v = _mm256_loadu_si256(bytes.as_ptr().add(base) as *const __m256i);
+        let shifted = _mm256_srli_epi16(v, 1);
+        let encoded = _mm256_and_si256(shifted, mask);
+        _mm256_storeu_si256(out.as_mut_ptr().add(base) as *mut __m256i, encoded);
+    }
+    for i in (chunks * 32)..bytes.len() {
+        out[i] = (bytes[i] >> 1) & 0b11;
+    }
+    out
+}
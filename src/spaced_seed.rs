@@ -0,0 +1,60 @@
+// Counting over k-mer gap patterns ("spaced seeds"): instead of every base in
+// a window contributing to the key, a fixed mask of don't-care positions is
+// skipped. Useful for tolerating scattered mismatches that a contiguous
+// k-mer would treat as entirely distinct sequences.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::{Sequence, SeqCounts};
+use rayon::prelude::*;
+
+// Types ----------------------------------------------------------------------
+/// A spaced seed mask, e.g. `"11011"` keeps positions 0, 1, 3, 4 of each
+/// window and treats position 2 as a don't-care gap.
+pub struct SpacedSeed {
+    pattern: Vec<bool>,
+}
+
+// Public Functions -------------------------------------------------------------
+impl SpacedSeed {
+    pub fn parse(pattern: &str) -> Self {
+        Self { pattern: pattern.chars().map(|c| c == '1').collect() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.pattern.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pattern.is_empty()
+    }
+
+    /// Number of positions actually included in the key (the seed's weight).
+    pub fn weight(&self) -> usize {
+        self.pattern.iter().filter(|&&keep| keep).count()
+    }
+}
+
+pub fn count(seed: &SpacedSeed, genome: &[u8]) -> SeqCounts {
+    if genome.len() < seed.len() {
+        return SeqCounts::default();
+    }
+    genome
+        .par_windows(seed.len())
+        .fold(SeqCounts::default, |mut counts, window| {
+            let seq = Sequence::from_key(seed_key(seed, window));
+            *counts.entry(seq).or_insert(0) += 1;
+            counts
+        })
+        .reduce(SeqCounts::default, crate::knucleotide::merge)
+}
+
+// Private Functions ------------------------------------------------------------
+fn seed_key(seed: &SpacedSeed, window: &[u8]) -> u64 {
+    let mut key = 0u64;
+    for (&keep, &byte) in seed.pattern.iter().zip(window) {
+        if keep {
+            key = (key << 2) | ((byte >> 1) & 0b11) as u64;
+        }
+    }
+    key
+}
@@ -0,0 +1,68 @@
+// Record/length statistics for FASTA input: cheap to compute during the
+// existing parse pass and among the most commonly needed reports.
+
+// Imports --------------------------------------------------------------------
+use crate::fasta::FastaRecord;
+
+// Types ----------------------------------------------------------------------
+pub struct Stats {
+    pub record_count: usize,
+    pub total_bases: usize,
+    pub n50: usize,
+    pub length_histogram: Vec<(usize, usize)>,
+    pub per_record_gc: Vec<RecordGc>,
+}
+
+/// A single record's composition, traceable back to its originating header.
+pub struct RecordGc {
+    pub id: String,
+    pub desc: String,
+    pub gc: f32,
+}
+
+// Public Functions -------------------------------------------------------------
+pub fn compute(records: &[FastaRecord]) -> Stats {
+    let mut lengths: Vec<usize> = records.iter().map(|r| r.seq.len()).collect();
+    let total_bases: usize = lengths.iter().sum();
+
+    Stats {
+        record_count: records.len(),
+        total_bases,
+        n50: n50(&mut lengths, total_bases),
+        length_histogram: histogram(&lengths),
+        per_record_gc: records
+            .iter()
+            .map(|r| RecordGc {
+                id: r.id.clone(),
+                desc: r.desc.clone(),
+                gc: crate::fasta::gc_content(&r.seq),
+            })
+            .collect(),
+    }
+}
+
+// Private Functions ------------------------------------------------------------
+fn n50(lengths: &mut [usize], total_bases: usize) -> usize {
+    lengths.sort_unstable_by(|l, r| r.cmp(l));
+    let mut cumulative = 0;
+    for &len in lengths.iter() {
+        cumulative += len;
+        if cumulative * 2 >= total_bases {
+            return len;
+        }
+    }
+    0
+}
+
+fn histogram(lengths: &[usize]) -> Vec<(usize, usize)> {
+    let mut buckets: Vec<(usize, usize)> = Vec::new();
+    for &len in lengths {
+        let bucket = len.next_power_of_two();
+        match buckets.iter_mut().find(|(b, _)| *b == bucket) {
+            Some((_, count)) => *count += 1,
+            None => buckets.push((bucket, 1)),
+        }
+    }
+    buckets.sort_by_key(|(bucket, _)| *bucket);
+    buckets
+}
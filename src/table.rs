@@ -0,0 +1,67 @@
+// Public arithmetic over count tables, so pipelines composing their own
+// results (e.g. per-chromosome then whole-genome) don't reimplement table
+// math on top of the packed `Sequence` representation.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::SeqCounts;
+use rayon::prelude::*;
+use std::io::{self, Write};
+
+// Public Functions -------------------------------------------------------------
+pub fn merge(counts: SeqCounts, other: SeqCounts) -> SeqCounts {
+    crate::knucleotide::merge(counts, other)
+}
+
+/// Adds `other`'s counts into `counts` in place.
+pub fn add_assign(counts: &mut SeqCounts, other: &SeqCounts) {
+    for (seq, count) in other {
+        *counts.entry(*seq).or_insert(0) += count;
+    }
+}
+
+/// Subtracts `other`'s counts from `counts`, floored at zero per k-mer.
+pub fn saturating_sub(counts: &mut SeqCounts, other: &SeqCounts) {
+    for (seq, count) in other {
+        if let Some(entry) = counts.get_mut(seq) {
+            *entry = entry.saturating_sub(*count);
+        }
+    }
+}
+
+/// Decodes and sorts `counts` by descending count, ties broken lexically, so
+/// consumers never need to touch the packed `Sequence` representation.
+pub fn iter_sorted_by_count(k: usize, counts: &SeqCounts) -> Vec<(String, u32)> {
+    let mut decoded: Vec<(String, u32)> = counts.iter().map(|(seq, count)| (seq.to_str(k), *count)).collect();
+    decoded.sort_by(|(l_str, l_cnt), (r_str, r_cnt)| r_cnt.cmp(l_cnt).then_with(|| l_str.cmp(r_str)));
+    decoded
+}
+
+/// Decodes and sorts `counts` lexicographically by k-mer.
+pub fn iter_lexicographic(k: usize, counts: &SeqCounts) -> Vec<(String, u32)> {
+    let mut decoded: Vec<(String, u32)> = counts.iter().map(|(seq, count)| (seq.to_str(k), *count)).collect();
+    decoded.sort_by(|(l_str, _), (r_str, _)| l_str.cmp(r_str));
+    decoded
+}
+
+/// Like `iter_sorted_by_count`, but sorts with rayon's parallel sort and
+/// streams the result straight to `out` instead of building an intermediate
+/// `Vec` the caller has to hold onto — the difference that matters once
+/// `counts` is too large to comfortably sort on one thread.
+pub fn write_sorted_by_count<W: Write>(k: usize, counts: &SeqCounts, mut out: W) -> io::Result<()> {
+    let mut decoded: Vec<(String, u32)> = counts.iter().map(|(seq, count)| (seq.to_str(k), *count)).collect();
+    decoded.par_sort_unstable_by(|(l_str, l_cnt), (r_str, r_cnt)| r_cnt.cmp(l_cnt).then_with(|| l_str.cmp(r_str)));
+    for (seq_str, count) in decoded {
+        writeln!(out, "{}\t{}", count, seq_str)?;
+    }
+    Ok(())
+}
+
+/// Rescales every count so the table sums to `target_total` (e.g. to compare
+/// tables built from differently-sized inputs).
+pub fn normalize(counts: &SeqCounts, target_total: f64) -> hashbrown::HashMap<crate::knucleotide::Sequence, f64> {
+    let total: u32 = counts.values().sum();
+    counts
+        .iter()
+        .map(|(seq, count)| (*seq, *count as f64 * target_total / total as f64))
+        .collect()
+}
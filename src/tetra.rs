@@ -0,0 +1,47 @@
+// Tetranucleotide signature correlation, a classic metagenomic binning
+// signal: contigs from the same organism tend to share similar 4-mer
+// composition regardless of coverage, so correlating two contigs'
+// signatures is a cheap proxy for "these probably belong together."
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::SeqCounts;
+use hashbrown::HashMap;
+
+// Public Functions -------------------------------------------------------------
+/// A contig's tetranucleotide signature: each 4-mer's share of the total.
+pub fn signature(counts: &SeqCounts) -> HashMap<String, f64> {
+    let total: u32 = counts.values().sum();
+    counts
+        .iter()
+        .map(|(seq, &count)| (seq.to_str(4), if total == 0 { 0.0 } else { count as f64 / total as f64 }))
+        .collect()
+}
+
+/// Pearson correlation between two signatures, over the union of 4-mers
+/// either contig actually observed (absent 4-mers contribute zero).
+pub fn correlation(a: &HashMap<String, f64>, b: &HashMap<String, f64>) -> f64 {
+    let keys: hashbrown::HashSet<&String> = a.keys().chain(b.keys()).collect();
+    let n = keys.len() as f64;
+    if n == 0.0 {
+        return 0.0;
+    }
+
+    let (mean_a, mean_b) = (
+        keys.iter().map(|k| a.get(*k).copied().unwrap_or(0.0)).sum::<f64>() / n,
+        keys.iter().map(|k| b.get(*k).copied().unwrap_or(0.0)).sum::<f64>() / n,
+    );
+
+    let (mut cov, mut var_a, mut var_b) = (0.0, 0.0, 0.0);
+    for key in keys {
+        let (da, db) = (a.get(key).copied().unwrap_or(0.0) - mean_a, b.get(key).copied().unwrap_or(0.0) - mean_b);
+        cov += da * db;
+        var_a += da * da;
+        var_b += db * db;
+    }
+
+    if var_a == 0.0 || var_b == 0.0 {
+        0.0
+    } else {
+        cov / (var_a.sqrt() * var_b.sqrt())
+    }
+}
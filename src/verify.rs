@@ -0,0 +1,43 @@
+// Debug verification that the packed `Sequence` key never collides on real
+// input: counts from the packed representation are cross-checked against
+// plain string-keyed counting on a sampled subset of windows.
+
+// Imports --------------------------------------------------------------------
+use crate::knucleotide::count_k;
+use hashbrown::HashMap;
+
+// Public Functions -------------------------------------------------------------
+/// Recomputes counts for a sampled prefix of `genome` using string keys and
+/// compares them against the packed counts produced by `count_k`. Returns
+/// any k-mers whose counts disagree, which would indicate an encoding or
+/// masking regression.
+pub fn verify_sample(k: usize, genome: &[u8], sample_len: usize) -> Vec<(String, u32, u32)> {
+    let sample = &genome[..sample_len.min(genome.len())];
+    let packed: HashMap<String, u32> = count_k(k, sample)
+        .into_iter()
+        .map(|(seq, count)| (seq.to_str(k), count))
+        .collect();
+    let stringly = count_k_stringly(k, sample);
+
+    let mut mismatches = Vec::new();
+    for (kmer, string_count) in &stringly {
+        let packed_count = packed.get(kmer).copied().unwrap_or(0);
+        if packed_count != *string_count {
+            mismatches.push((kmer.clone(), packed_count, *string_count));
+        }
+    }
+    mismatches
+}
+
+// Private Functions ------------------------------------------------------------
+fn count_k_stringly(k: usize, genome: &[u8]) -> HashMap<String, u32> {
+    let mut counts = HashMap::new();
+    if genome.len() < k {
+        return counts;
+    }
+    for window in genome.windows(k) {
+        let kmer = String::from_utf8_lossy(window).into_owned();
+        *counts.entry(kmer).or_insert(0) += 1;
+    }
+    counts
+}